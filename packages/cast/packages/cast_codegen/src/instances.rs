@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Group, TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
+use syn::{
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Error, GenericArgument, Ident, ItemImpl, PathArguments, Result, Token, Type,
+};
+
+const CAST_IDENT: &str = "cast";
+const INSTANCES_IDENT: &str = "instances";
+
+/// instances(Repo<User>, Repo<Order>)
+fn parse_instances(input: ParseStream) -> Result<Vec<Type>> {
+    let ident = input.parse::<Ident>()?;
+
+    if ident != INSTANCES_IDENT {
+        return Err(Error::new(ident.span(), "Expected instances(...)"));
+    }
+
+    let content;
+    syn::parenthesized!(content in input);
+
+    let instances = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+
+    Ok(instances.into_iter().collect())
+}
+
+/// Parses the instances of the `#[cast(instances(...))]` macro arguments
+pub(crate) fn parse_instances_args(args: TokenStream2) -> Result<Vec<Type>> {
+    if args.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    parse_instances.parse2(args)
+}
+
+/// Takes the instances of the `#[cast(instances(...))]` helper attributes
+pub(crate) fn get_instances(attrs: &mut Vec<Attribute>) -> Result<Vec<Type>> {
+    let mut instances: Vec<Type> = Vec::new();
+
+    attrs.retain(|attr| {
+        if !attr.path.is_ident(CAST_IDENT) {
+            return true;
+        }
+
+        match attr.parse_args_with(parse_instances) {
+            Ok(attr_instances) => {
+                instances.extend(attr_instances);
+                false
+            }
+            Err(_) => true,
+        }
+    });
+
+    Ok(instances)
+}
+
+pub(crate) fn get_type_params(impl_block: &ItemImpl) -> Vec<Ident> {
+    impl_block
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect()
+}
+
+fn get_type_args(ty: &Type) -> Option<Vec<&Type>> {
+    let path = match ty {
+        Type::Path(type_path) => &type_path.path,
+        _ => return None,
+    };
+
+    match &path.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) => Some(
+            args.args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        ),
+        PathArguments::None => Some(Vec::new()),
+        PathArguments::Parenthesized(_) => None,
+    }
+}
+
+/// Matches the type params of the impl block with the type args of the instance, e.g. `T` of `Repo<T>` with `User` of `Repo<User>`
+pub(crate) fn bind_type_params(
+    self_ty: &Type,
+    type_params: &[Ident],
+    instance: &Type,
+) -> Result<HashMap<String, TokenStream2>> {
+    let error = || {
+        Error::new(
+            instance.span(),
+            format!(
+                "The {} instance doesn't match {}",
+                instance.to_token_stream(),
+                self_ty.to_token_stream()
+            ),
+        )
+    };
+
+    let self_args = get_type_args(self_ty).ok_or_else(error)?;
+    let instance_args = get_type_args(instance).ok_or_else(error)?;
+
+    if self_args.len() != instance_args.len() {
+        return Err(error());
+    }
+
+    let mut bindings: HashMap<String, TokenStream2> = HashMap::new();
+
+    for (self_arg, instance_arg) in self_args.iter().zip(instance_args.iter()) {
+        if let Some(param) = type_params
+            .iter()
+            .find(|param| self_arg.to_token_stream().to_string() == param.to_string())
+        {
+            bindings.insert(param.to_string(), instance_arg.to_token_stream());
+        }
+    }
+
+    for param in type_params {
+        if !bindings.contains_key(&param.to_string()) {
+            return Err(Error::new(
+                instance.span(),
+                format!(
+                    "The {} type param can't be inferred from the {} instance",
+                    param,
+                    instance.to_token_stream()
+                ),
+            ));
+        }
+    }
+
+    Ok(bindings)
+}
+
+/// Replaces the type params by the bound types, e.g. `IRepo<T>` by `IRepo<User>`
+pub(crate) fn substitute_type_params(
+    tokens: TokenStream2,
+    bindings: &HashMap<String, TokenStream2>,
+) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Ident(ident) => match bindings.get(&ident.to_string()) {
+                Some(ty) => ty.clone(),
+                None => TokenTree::Ident(ident).into_token_stream(),
+            },
+            TokenTree::Group(group) => {
+                let mut substituted = Group::new(
+                    group.delimiter(),
+                    substitute_type_params(group.stream(), bindings),
+                );
+
+                substituted.set_span(group.span());
+
+                TokenTree::Group(substituted).into_token_stream()
+            }
+            token => token.into_token_stream(),
+        })
+        .collect()
+}
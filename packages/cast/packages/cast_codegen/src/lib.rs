@@ -1,17 +1,40 @@
 mod helpers;
+mod instances;
 mod path;
 
 use helpers::{gen_fn_name, get_trait_path};
+use instances::{
+    bind_type_params, get_instances, get_type_params, parse_instances_args, substitute_type_params,
+};
 use path::get_path_to_lib;
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::ToTokens;
+use syn::{spanned::Spanned, Error};
 
+/// Registers the casters of the impl block.
+///
+/// The casters need concrete types, so the generic impl blocks list their instances:
+///
+/// ```rust
+/// #[cast(instances(Repo<User>, Repo<Order>))]
+/// impl<T: Entity> IRepo<T> for Repo<T> {}
+/// ```
 #[proc_macro_attribute]
-pub fn cast(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn cast(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match gen_cast_code(attr.into(), item) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn gen_cast_code(attr: TokenStream2, item: TokenStream) -> Result<TokenStream2, Error> {
     let mut impl_block =
         syn::parse::<syn::ItemImpl>(item.clone()).expect("#[cast] can be used only on impl blocks");
 
+    let mut instances = parse_instances_args(attr)?;
+    instances.extend(get_instances(&mut impl_block.attrs)?);
+
     let path_to_lib = get_path_to_lib(&mut impl_block.attrs).expect("Can't get the lib path");
 
     let mut result = TokenStream2::from(impl_block.to_token_stream());
@@ -21,14 +44,47 @@ pub fn cast(_attr: TokenStream, item: TokenStream) -> TokenStream {
         .expect("#[cast] can be used only on impl blocks for traits")
         .to_token_stream();
 
-    result.extend(create_caster(&ty, &ty, &path_to_lib));
-    result.extend(create_caster(
-        &quote::quote! { dyn #trait_ },
-        &ty,
-        &path_to_lib,
-    ));
+    let type_params = get_type_params(&impl_block);
 
-    return result.into();
+    if type_params.is_empty() {
+        if let Some(instance) = instances.first() {
+            return Err(Error::new(
+                instance.span(),
+                "instances(...) can be used only on generic impl blocks",
+            ));
+        }
+
+        result.extend(create_caster(&ty, &ty, &path_to_lib));
+        result.extend(create_caster(
+            &quote::quote! { dyn #trait_ },
+            &ty,
+            &path_to_lib,
+        ));
+
+        return Ok(result);
+    }
+
+    if instances.is_empty() {
+        return Err(Error::new(
+            impl_block.generics.span(),
+            "#[cast] requires #[cast(instances(...))] for generic impl blocks",
+        ));
+    }
+
+    for instance in instances {
+        let bindings = bind_type_params(&impl_block.self_ty, &type_params, &instance)?;
+        let instance_ty = instance.to_token_stream();
+        let instance_trait = substitute_type_params(trait_.clone(), &bindings);
+
+        result.extend(create_caster(&instance_ty, &instance_ty, &path_to_lib));
+        result.extend(create_caster(
+            &quote::quote! { dyn #instance_trait },
+            &instance_ty,
+            &path_to_lib,
+        ));
+    }
+
+    Ok(result)
 }
 
 fn create_caster(
@@ -37,7 +93,11 @@ fn create_caster(
     path_to_lib: &TokenStream2,
 ) -> TokenStream2 {
     let trait_caster = quote::quote! {
-        #path_to_lib::Caster::<#from>::new(
+        #path_to_lib::Caster::<#from>::new::<#to>(
+            |from| #path_to_lib::downcast_arc::<#to>(from),
+            |from| from.downcast_ref::<#to>().unwrap(),
+            |from| from.downcast_mut::<#to>().unwrap(),
+            |from| from.downcast::<#to>().unwrap(),
             |from| from.downcast::<#to>().unwrap(),
         )
     };
@@ -1,28 +1,135 @@
-use std::{any::Any, sync::Arc};
+use std::{any::Any, rc::Rc, sync::Arc};
 
-use crate::{cast_from::CastFrom, error::Error, vtable::get_trait_caster};
+use crate::{
+    cast_from::{CastFrom, CastFromAny},
+    caster::Caster,
+    error::Error,
+    registry::CasterRegistry,
+    vtable::get_trait_caster,
+};
 
-pub trait Cast {
-    fn cast<T: ?Sized + Any>(self: Arc<Self>) -> Result<Arc<T>, Error>;
-    fn is<T: ?Sized + Any>(self: Arc<Self>) -> bool;
+pub trait Cast: CastFromAny {
+    fn cast<T: ?Sized + Any>(self: Arc<Self>) -> Result<Arc<T>, Error>
+    where
+        Self: CastFrom;
+    fn is<T: ?Sized + Any>(self: Arc<Self>) -> bool
+    where
+        Self: CastFrom;
+    fn cast_box<T: ?Sized + Any>(self: Box<Self>) -> Result<Box<T>, Error>;
+    fn cast_rc<T: ?Sized + Any>(self: Rc<Self>) -> Result<Rc<T>, Error>;
 }
 
-impl<S: ?Sized + CastFrom> Cast for S {
-    fn cast<T: ?Sized + Any>(self: Arc<Self>) -> Result<Arc<T>, Error> {
-        match get_trait_caster::<T>((*self).type_id()) {
-            Some(caster) => Ok((caster.cast_fn)(self.as_any())),
-            None => Err(Error::new(
-                format!(
-                    "Not found {} trait caster for {}",
-                    std::any::type_name::<T>(),
-                    std::any::type_name::<S>()
-                )
-                .as_str(),
-            )),
-        }
+/// Casts the pointed object by reference, `Box`, `Arc` and `Rc` are cast as the object they point to
+pub trait CastRef {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T>;
+}
+
+/// Casts the pointed object by mutable reference, `Box` is cast as the object it points to
+pub trait CastMut {
+    fn cast_mut<T: ?Sized + Any>(&mut self) -> Option<&mut T>;
+}
+
+/// Casts the reference, unlike `CastRef::cast_ref` the result lives as long as the reference
+pub fn cast_ref<T: ?Sized + Any, S: ?Sized + CastFromAny>(from: &S) -> Option<&T> {
+    let caster = get_caster::<S, T>(from).ok()?;
+
+    Some((caster.cast_ref_fn)(from.as_any_ref()))
+}
+
+/// Casts the mutable reference, unlike `CastMut::cast_mut` the result lives as long as the reference
+pub fn cast_mut<T: ?Sized + Any, S: ?Sized + CastFromAny>(from: &mut S) -> Option<&mut T> {
+    let caster = get_caster::<S, T>(from).ok()?;
+
+    Some((caster.cast_mut_fn)(from.as_any_mut()))
+}
+
+impl<S: ?Sized + CastFromAny> CastRef for &S {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T> {
+        cast_ref(*self)
+    }
+}
+
+impl<S: ?Sized + CastFromAny> CastRef for &mut S {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T> {
+        cast_ref(&**self)
+    }
+}
+
+impl<S: ?Sized + CastFromAny> CastRef for Box<S> {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T> {
+        cast_ref(&**self)
+    }
+}
+
+impl<S: ?Sized + CastFromAny> CastRef for Arc<S> {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T> {
+        cast_ref(&**self)
+    }
+}
+
+impl<S: ?Sized + CastFromAny> CastRef for Rc<S> {
+    fn cast_ref<T: ?Sized + Any>(&self) -> Option<&T> {
+        cast_ref(&**self)
+    }
+}
+
+impl<S: ?Sized + CastFromAny> CastMut for &mut S {
+    fn cast_mut<T: ?Sized + Any>(&mut self) -> Option<&mut T> {
+        cast_mut(&mut **self)
     }
+}
 
-    fn is<T: ?Sized + 'static>(self: Arc<Self>) -> bool {
+impl<S: ?Sized + CastFromAny> CastMut for Box<S> {
+    fn cast_mut<T: ?Sized + Any>(&mut self) -> Option<&mut T> {
+        cast_mut(&mut **self)
+    }
+}
+
+fn get_caster<S: ?Sized + CastFromAny, T: ?Sized + Any>(
+    from: &S,
+) -> Result<&'static Caster<T>, Error> {
+    let type_id = from.as_any_ref().type_id();
+
+    get_trait_caster::<T>(type_id).ok_or_else(|| {
+        let casters = CasterRegistry::global().get_casters(type_id);
+
+        Error::not_found(
+            casters
+                .first()
+                .map(|caster| caster.type_name)
+                .unwrap_or_else(|| std::any::type_name::<S>()),
+            std::any::type_name::<T>(),
+            casters.iter().map(|caster| caster.trait_name).collect(),
+        )
+    })
+}
+
+impl<S: ?Sized + CastFromAny> Cast for S {
+    fn cast<T: ?Sized + Any>(self: Arc<Self>) -> Result<Arc<T>, Error>
+    where
+        Self: CastFrom,
+    {
+        let caster = get_caster::<S, T>(&*self)?;
+
+        Ok((caster.cast_fn)(self.as_any()))
+    }
+
+    fn is<T: ?Sized + 'static>(self: Arc<Self>) -> bool
+    where
+        Self: CastFrom,
+    {
         self.cast::<T>().is_ok()
     }
+
+    fn cast_box<T: ?Sized + Any>(self: Box<Self>) -> Result<Box<T>, Error> {
+        let caster = get_caster::<S, T>(&*self)?;
+
+        Ok((caster.cast_box_fn)(self.as_any_box()))
+    }
+
+    fn cast_rc<T: ?Sized + Any>(self: Rc<Self>) -> Result<Rc<T>, Error> {
+        let caster = get_caster::<S, T>(&*self)?;
+
+        Ok((caster.cast_rc_fn)(self.as_any_rc()))
+    }
 }
@@ -1,6 +1,34 @@
-use std::{any::Any, sync::Arc};
+use std::{any::Any, rc::Rc, sync::Arc};
 
-pub trait CastFrom: Any + Send + Sync {
+/// Base of the castable traits, implemented for all types including `!Send` ones.
+///
+/// The traits of the `!Send` types extend it to be cast by references, `Box` and `Rc`.
+pub trait CastFromAny: Any {
+    fn as_any_ref(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn as_any_box(self: Box<Self>) -> Box<dyn Any>;
+    fn as_any_rc(self: Rc<Self>) -> Rc<dyn Any>;
+}
+
+impl<T: Sized + Any> CastFromAny for T {
+    fn as_any_ref(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn as_any_box(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any_rc(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+}
+
+pub trait CastFrom: CastFromAny + Send + Sync {
     fn as_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
 }
 
@@ -1,11 +1,98 @@
-use std::{any::Any, sync::Arc};
+use std::{
+    any::{Any, TypeId},
+    rc::Rc,
+    sync::Arc,
+};
 
 pub struct Caster<T: ?Sized + Any> {
+    pub type_id: TypeId,
+    pub type_name: &'static str,
     pub cast_fn: fn(from: Arc<dyn Any + Sync + Send>) -> Arc<T>,
+    pub cast_ref_fn: fn(from: &dyn Any) -> &T,
+    pub cast_mut_fn: fn(from: &mut dyn Any) -> &mut T,
+    pub cast_box_fn: fn(from: Box<dyn Any>) -> Box<T>,
+    pub cast_rc_fn: fn(from: Rc<dyn Any>) -> Rc<T>,
 }
 
 impl<T: ?Sized + Any> Caster<T> {
-    pub fn new(cast_fn: fn(from: Arc<dyn Any + Sync + Send>) -> Arc<T>) -> Self {
-        Self { cast_fn }
+    /// Creates the caster from the `S` type to the `T` trait, use `caster!(S => dyn T)` to generate the functions
+    pub fn new<S: Any>(
+        cast_fn: fn(from: Arc<dyn Any + Sync + Send>) -> Arc<T>,
+        cast_ref_fn: fn(from: &dyn Any) -> &T,
+        cast_mut_fn: fn(from: &mut dyn Any) -> &mut T,
+        cast_box_fn: fn(from: Box<dyn Any>) -> Box<T>,
+        cast_rc_fn: fn(from: Rc<dyn Any>) -> Rc<T>,
+    ) -> Self {
+        Self {
+            type_id: TypeId::of::<S>(),
+            type_name: std::any::type_name::<S>(),
+            cast_fn,
+            cast_ref_fn,
+            cast_mut_fn,
+            cast_box_fn,
+            cast_rc_fn,
+        }
     }
 }
+
+/// Caster with the erased trait type
+pub trait AnyCaster: Any + Send + Sync {
+    fn get_type_name(&self) -> &'static str;
+    fn get_trait_name(&self) -> &'static str;
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: ?Sized + Any> AnyCaster for Caster<T> {
+    fn get_type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    fn get_trait_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Downcasts the `Arc` without the `Send + Sync` bound of `Arc::downcast`, so the casters of the `!Send` types compile
+pub fn downcast_arc<T: Any>(from: Arc<dyn Any + Sync + Send>) -> Arc<T> {
+    assert_eq!((*from).type_id(), TypeId::of::<T>());
+
+    // the type is checked above
+    unsafe { Arc::from_raw(Arc::into_raw(from) as *const T) }
+}
+
+/// Creates the `Caster` from the type to the trait:
+///
+/// ```rust,ignore
+/// let caster = caster!(Service => dyn IService);
+/// ```
+#[macro_export]
+macro_rules! caster {
+    ($from:ty => $to:ty) => {
+        $crate::Caster::<$to>::new::<$from>(
+            |from| $crate::downcast_arc::<$from>(from),
+            |from| from.downcast_ref::<$from>().unwrap(),
+            |from| from.downcast_mut::<$from>().unwrap(),
+            |from| from.downcast::<$from>().unwrap(),
+            |from| from.downcast::<$from>().unwrap(),
+        )
+    };
+}
+
+/// Registers the casters from the type to the trait and to the type itself in the global `CasterRegistry`:
+///
+/// ```rust,ignore
+/// register_caster!(Service => dyn IService);
+/// ```
+#[macro_export]
+macro_rules! register_caster {
+    ($from:ty => $to:ty) => {{
+        let registry = $crate::CasterRegistry::global();
+
+        registry.register($crate::caster!($from => $from));
+        registry.register($crate::caster!($from => $to));
+    }};
+}
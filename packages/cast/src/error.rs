@@ -1,14 +1,53 @@
 #[derive(Debug)]
 pub struct Error {
     msg: String,
+    type_name: Option<&'static str>,
+    trait_name: Option<&'static str>,
+    registered_traits: Vec<&'static str>,
 }
 
 impl Error {
-    pub fn new<'a>(msg: &'a str) -> Self {
+    pub fn new(msg: &str) -> Self {
         Self {
             msg: String::from(msg),
+            type_name: None,
+            trait_name: None,
+            registered_traits: Vec::new(),
         }
     }
+
+    /// The error of the cast which trait caster is not registered for the type
+    pub fn not_found(
+        type_name: &'static str,
+        trait_name: &'static str,
+        registered_traits: Vec<&'static str>,
+    ) -> Self {
+        Self {
+            msg: format!(
+                "Not found {} trait caster for {}, registered traits: [{}]",
+                trait_name,
+                type_name,
+                registered_traits.join(", ")
+            ),
+            type_name: Some(type_name),
+            trait_name: Some(trait_name),
+            registered_traits,
+        }
+    }
+
+    /// Name of the source type, the concrete one if any of its casters is registered
+    pub fn get_type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    pub fn get_trait_name(&self) -> Option<&'static str> {
+        self.trait_name
+    }
+
+    /// Traits the source type can be cast to
+    pub fn get_registered_traits(&self) -> &Vec<&'static str> {
+        &self.registered_traits
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -16,3 +55,5 @@ impl std::fmt::Display for Error {
         write!(f, "TraitCastingError: {}", &self.msg.as_str())
     }
 }
+
+impl std::error::Error for Error {}
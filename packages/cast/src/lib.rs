@@ -2,15 +2,20 @@ mod cast;
 mod cast_from;
 mod caster;
 mod error;
+mod registry;
 mod tests;
 mod vtable;
 
 pub use blackbox_cast_codegen::cast;
 
-pub use cast::Cast;
-pub use cast_from::CastFrom;
-pub use caster::Caster;
+pub use cast::{cast_mut, cast_ref, Cast, CastMut, CastRef};
+pub use cast_from::{CastFrom, CastFromAny};
+pub use caster::{downcast_arc, AnyCaster, Caster};
 pub use error::Error;
-pub use vtable::{BoxedTraitCaster, CasterId, TargetId, TRAITCASTERS};
+pub use registry::{CasterInfo, CasterRegistry, CasterSource};
+pub use vtable::{
+    castable_to, implemented_traits, implementors_of, BoxedTraitCaster, CasterId, TargetId,
+    TRAITCASTERS,
+};
 
 pub use linkme;
@@ -0,0 +1,175 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    caster::{AnyCaster, Caster},
+    vtable::{get_trait_caster_id, BoxedTraitCaster, CasterId, TargetId, TRAITCASTERS},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasterSource {
+    /// Registered by `#[cast]` in the `TRAITCASTERS` slice
+    Static,
+    /// Registered by `CasterRegistry::register`
+    Runtime,
+}
+
+/// Diagnostic info of the registered caster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CasterInfo {
+    pub type_name: &'static str,
+    pub trait_name: &'static str,
+    pub source: CasterSource,
+}
+
+impl Display for CasterInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} -> {} ({:?})",
+            self.type_name, self.trait_name, self.source
+        )
+    }
+}
+
+type CasterKey = (TargetId, CasterId);
+
+static REGISTRY: Lazy<CasterRegistry> = Lazy::new(|| {
+    let static_casters = TRAITCASTERS
+        .iter()
+        .map(|caster_fn| {
+            let (target_id, caster) = caster_fn();
+
+            ((target_id, caster.as_any().type_id()), caster)
+        })
+        .collect();
+
+    CasterRegistry {
+        static_casters,
+        runtime_casters: RwLock::new(HashMap::new()),
+        has_runtime_casters: AtomicBool::new(false),
+    }
+});
+
+/// Casters used by `Cast`, the casters of the `TRAITCASTERS` slice are merged with the ones registered at runtime.
+///
+/// The linkers can drop the unreferenced sections of the slice, e.g. in static libs, register such casters manually.
+/// The slice casters are looked up first without locking, the runtime ones only if any is registered.
+pub struct CasterRegistry {
+    static_casters: HashMap<CasterKey, BoxedTraitCaster>,
+    runtime_casters: RwLock<HashMap<CasterKey, &'static dyn AnyCaster>>,
+    has_runtime_casters: AtomicBool,
+}
+
+impl CasterRegistry {
+    pub fn global() -> &'static CasterRegistry {
+        &REGISTRY
+    }
+
+    /// Registers the caster, use `caster!(Service => dyn IService)` to create it.
+    ///
+    /// Returns `false` and drops the caster if the caster of the same type and trait is already registered.
+    pub fn register<T: ?Sized + Any>(&self, caster: Caster<T>) -> bool {
+        let key = (caster.type_id, get_trait_caster_id::<T>());
+
+        if self.static_casters.contains_key(&key) {
+            return false;
+        }
+
+        let mut runtime_casters = self.runtime_casters.write().unwrap();
+
+        if runtime_casters.contains_key(&key) {
+            return false;
+        }
+
+        // the registered casters live as long as the registry, it's static
+        runtime_casters.insert(key, Box::leak(Box::new(caster)));
+        self.has_runtime_casters.store(true, Ordering::Release);
+
+        true
+    }
+
+    pub fn get<T: ?Sized + Any>(&self, target_id: TargetId) -> Option<&Caster<T>> {
+        let key = (target_id, get_trait_caster_id::<T>());
+
+        if let Some(caster) = self.static_casters.get(&key) {
+            return caster.as_any().downcast_ref::<Caster<T>>();
+        }
+
+        if !self.has_runtime_casters.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let caster: &'static dyn AnyCaster = *self.runtime_casters.read().unwrap().get(&key)?;
+
+        caster.as_any().downcast_ref::<Caster<T>>()
+    }
+
+    /// Lists the casters found for the type, sorted by the trait name
+    pub fn get_casters(&self, target_id: TargetId) -> Vec<CasterInfo> {
+        let static_casters = self
+            .static_casters
+            .iter()
+            .map(|(key, caster)| (key, caster.as_ref(), CasterSource::Static));
+
+        let runtime_casters = self.runtime_casters.read().unwrap();
+        let runtime_casters = runtime_casters
+            .iter()
+            .map(|(key, caster)| (key, *caster, CasterSource::Runtime));
+
+        let mut infos = static_casters
+            .chain(runtime_casters)
+            .filter(|((id, _), _, _)| *id == target_id)
+            .map(|(_, caster, source)| CasterInfo {
+                type_name: caster.get_type_name(),
+                trait_name: caster.get_trait_name(),
+                source,
+            })
+            .collect::<Vec<_>>();
+
+        infos.sort_by(|a, b| a.trait_name.cmp(b.trait_name));
+
+        infos
+    }
+
+    /// Lists the names of the traits the type can be cast to
+    pub fn implemented_traits(&self, target_id: TargetId) -> Vec<&'static str> {
+        self.get_casters(target_id)
+            .into_iter()
+            .map(|caster| caster.trait_name)
+            .collect()
+    }
+
+    /// Lists the names of the traits the type can be cast to
+    pub fn castable_to<S: Any>(&self) -> Vec<&'static str> {
+        self.implemented_traits(TypeId::of::<S>())
+    }
+
+    /// Lists the names of the types that can be cast to the trait
+    pub fn implementors_of<T: ?Sized + Any>(&self) -> Vec<&'static str> {
+        let caster_id = get_trait_caster_id::<T>();
+        let runtime_casters = self.runtime_casters.read().unwrap();
+
+        let mut type_names = self
+            .static_casters
+            .iter()
+            .map(|(key, caster)| (key, caster.as_ref()))
+            .chain(runtime_casters.iter().map(|(key, caster)| (key, *caster)))
+            .filter(|((_, id), _)| *id == caster_id)
+            .map(|(_, caster)| caster.get_type_name())
+            .collect::<Vec<_>>();
+
+        type_names.sort();
+
+        type_names
+    }
+}
@@ -5,6 +5,9 @@ use std::sync::Arc;
 use crate::cast;
 use crate::Cast;
 use crate::CastFrom;
+use crate::CastFromAny;
+use crate::CastMut;
+use crate::CastRef;
 
 trait Injectable: CastFrom {}
 trait IService: CastFrom {}
@@ -34,3 +37,301 @@ fn cast_traits_among_themselves() {
     let interface_2 = interface.cast::<dyn Injectable>().unwrap();
     let initial_service = interface_2.cast::<Service>().unwrap();
 }
+
+trait Entity: Send + Sync + 'static {
+    fn name() -> &'static str;
+}
+
+struct User {}
+struct Order {}
+
+impl Entity for User {
+    fn name() -> &'static str {
+        "user"
+    }
+}
+
+impl Entity for Order {
+    fn name() -> &'static str {
+        "order"
+    }
+}
+
+trait IRepo<T: Entity>: CastFrom {
+    fn entity_name(&self) -> &'static str {
+        T::name()
+    }
+}
+
+struct Repo<T: Entity> {
+    _entity: std::marker::PhantomData<T>,
+}
+
+impl<T: Entity> Repo<T> {
+    fn new() -> Repo<T> {
+        Repo {
+            _entity: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cast(instances(Repo<User>, Repo<Order>))]
+#[cast(crate)]
+impl<T: Entity> IRepo<T> for Repo<T> {}
+
+#[cast]
+#[cast(crate)]
+#[cast(instances(Repo<User>, Repo<Order>))]
+impl<T: Entity> Injectable for Repo<T> {}
+
+struct UserRepo {}
+
+#[cast]
+#[cast(crate)]
+impl IRepo<User> for UserRepo {}
+
+#[test]
+fn cast_generic_impl_instances() {
+    let user_repo = Arc::new(Repo::<User>::new());
+    let repo = user_repo.cast::<dyn IRepo<User>>().unwrap();
+
+    assert_eq!(repo.entity_name(), "user");
+
+    let injectable = repo.cast::<dyn Injectable>().unwrap();
+    let initial_repo = injectable.cast::<Repo<User>>().unwrap();
+
+    let order_repo = Arc::new(Repo::<Order>::new());
+    let repo = order_repo.clone().cast::<dyn IRepo<Order>>().unwrap();
+
+    assert_eq!(repo.entity_name(), "order");
+    assert!(!order_repo.clone().is::<dyn IRepo<User>>());
+    assert!(order_repo.cast::<Repo<User>>().is_err());
+}
+
+#[test]
+fn cast_to_generic_traits() {
+    let repo = Arc::new(UserRepo {});
+    let user_repo = repo.cast::<dyn IRepo<User>>().unwrap();
+
+    assert_eq!(user_repo.entity_name(), "user");
+    assert!(user_repo.clone().cast::<dyn IRepo<Order>>().is_err());
+
+    let initial_repo = user_repo.cast::<UserRepo>().unwrap();
+}
+
+trait ICounter: CastFromAny {
+    fn get(&self) -> u32;
+}
+
+trait IIncrement: CastFromAny {
+    fn increment(&mut self);
+}
+
+// !Send + !Sync
+struct LocalCounter {
+    value: std::rc::Rc<std::cell::Cell<u32>>,
+}
+
+#[cast]
+#[cast(crate)]
+impl ICounter for LocalCounter {
+    fn get(&self) -> u32 {
+        self.value.get()
+    }
+}
+
+#[cast]
+#[cast(crate)]
+impl IIncrement for LocalCounter {
+    fn increment(&mut self) {
+        self.value.set(self.value.get() + 1);
+    }
+}
+
+fn new_local_counter() -> LocalCounter {
+    LocalCounter {
+        value: std::rc::Rc::new(std::cell::Cell::new(0)),
+    }
+}
+
+#[test]
+fn cast_references() {
+    let mut counter: Box<dyn IIncrement> = Box::new(new_local_counter());
+
+    counter.cast_mut::<dyn IIncrement>().unwrap().increment();
+
+    let counter_ref = crate::cast_ref::<dyn ICounter, _>(&*counter).unwrap();
+
+    assert_eq!(counter_ref.get(), 1);
+    assert!(counter_ref.cast_ref::<LocalCounter>().is_some());
+    assert!(counter_ref.cast_ref::<dyn IService>().is_none());
+
+    let service = Service {};
+    let injectable: &dyn Injectable = &service;
+
+    assert!(injectable.cast_ref::<dyn IService>().is_some());
+}
+
+#[test]
+fn cast_smart_pointers_by_reference() {
+    let arc: Arc<dyn Injectable> = Arc::new(Service {});
+    let mut boxed: Box<dyn IIncrement> = Box::new(new_local_counter());
+    let rc: std::rc::Rc<dyn ICounter> = std::rc::Rc::new(new_local_counter());
+
+    // the pointed objects are cast, not the pointers
+    assert!(arc.cast_ref::<dyn IService>().is_some());
+    assert!(arc.cast_ref::<Service>().is_some());
+
+    boxed.cast_mut::<LocalCounter>().unwrap().increment();
+
+    assert_eq!(boxed.cast_ref::<dyn ICounter>().unwrap().get(), 1);
+
+    let boxed_ref = &boxed;
+
+    assert_eq!(boxed_ref.cast_ref::<dyn ICounter>().unwrap().get(), 1);
+    assert!(rc.cast_ref::<dyn IIncrement>().is_some());
+    assert!(rc.cast_ref::<dyn IService>().is_none());
+}
+
+#[test]
+fn cast_boxes() {
+    let counter: Box<dyn IIncrement> = Box::new(new_local_counter());
+    let mut counter = counter.cast_box::<dyn ICounter>().unwrap();
+
+    assert_eq!(counter.get(), 0);
+
+    let mut local_counter = counter.cast_box::<LocalCounter>().unwrap();
+
+    local_counter.increment();
+
+    assert_eq!(local_counter.get(), 1);
+    assert!(local_counter.cast_box::<dyn IService>().is_err());
+}
+
+#[test]
+fn cast_rcs_of_not_send_types() {
+    let counter = std::rc::Rc::new(new_local_counter());
+    let counter_ref = counter.clone().cast_rc::<dyn ICounter>().unwrap();
+
+    counter.value.set(5);
+
+    assert_eq!(counter_ref.get(), 5);
+
+    let initial_counter = counter_ref.cast_rc::<LocalCounter>().unwrap();
+
+    assert!(std::rc::Rc::ptr_eq(&initial_counter, &counter));
+    assert!(initial_counter.cast_rc::<dyn Injectable>().is_err());
+}
+
+// without #[cast]
+struct ManualService {}
+
+impl IService for ManualService {}
+impl Injectable for ManualService {}
+
+#[test]
+fn register_casters_at_runtime() {
+    let service = Arc::new(ManualService {});
+
+    assert!(!service.clone().is::<dyn IService>());
+
+    crate::register_caster!(ManualService => dyn IService);
+    crate::register_caster!(ManualService => dyn Injectable);
+
+    let interface = service.cast::<dyn IService>().unwrap();
+    let injectable = interface.cast::<dyn Injectable>().unwrap();
+    let initial_service = injectable.cast::<ManualService>().unwrap();
+
+    // the registered casters are not replaced
+    assert!(
+        !crate::CasterRegistry::global().register(crate::caster!(ManualService => dyn IService))
+    );
+    assert!(!crate::CasterRegistry::global().register(crate::caster!(Service => dyn IService)));
+
+    let casters = crate::CasterRegistry::global()
+        .get_casters(std::any::TypeId::of::<ManualService>())
+        .into_iter()
+        .map(|caster| (caster.trait_name, caster.source))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        casters,
+        vec![
+            (
+                std::any::type_name::<ManualService>(),
+                crate::CasterSource::Runtime
+            ),
+            (
+                std::any::type_name::<dyn IService>(),
+                crate::CasterSource::Runtime
+            ),
+            (
+                std::any::type_name::<dyn Injectable>(),
+                crate::CasterSource::Runtime
+            ),
+        ]
+    );
+}
+
+#[test]
+fn list_static_casters_of_type() {
+    let casters = crate::CasterRegistry::global().get_casters(std::any::TypeId::of::<Service>());
+
+    assert!(casters
+        .iter()
+        .all(|caster| caster.source == crate::CasterSource::Static));
+    assert!(casters
+        .iter()
+        .all(|caster| caster.type_name == std::any::type_name::<Service>()));
+    assert_eq!(casters.len(), 3);
+}
+
+#[test]
+fn list_implemented_traits() {
+    let traits = crate::implemented_traits(std::any::TypeId::of::<Service>());
+
+    assert!(traits.contains(&std::any::type_name::<dyn Injectable>()));
+    assert!(traits.contains(&std::any::type_name::<dyn IService>()));
+    assert!(!traits.contains(&std::any::type_name::<dyn IRepo<User>>()));
+    assert_eq!(crate::castable_to::<Service>(), traits);
+
+    let types = crate::implementors_of::<dyn IRepo<User>>();
+
+    assert_eq!(
+        types,
+        vec![
+            std::any::type_name::<Repo<User>>(),
+            std::any::type_name::<UserRepo>()
+        ]
+    );
+}
+
+#[test]
+fn describe_failed_cast_in_error() {
+    let injectable: Arc<dyn Injectable> = Arc::new(Service {});
+    let error = injectable.cast::<dyn IRepo<User>>().err().unwrap();
+
+    assert_eq!(
+        error.get_type_name(),
+        Some(std::any::type_name::<Service>())
+    );
+    assert_eq!(
+        error.get_trait_name(),
+        Some(std::any::type_name::<dyn IRepo<User>>())
+    );
+    assert_eq!(
+        error.get_registered_traits(),
+        &crate::implemented_traits(std::any::TypeId::of::<Service>())
+    );
+
+    let error: Box<dyn std::error::Error> = Box::new(error);
+
+    assert!(error
+        .to_string()
+        .contains(std::any::type_name::<dyn IService>()));
+
+    let error = crate::Error::new("custom caster failed");
+
+    assert_eq!(error.to_string(), "TraitCastingError: custom caster failed");
+    assert_eq!(error.get_type_name(), None);
+}
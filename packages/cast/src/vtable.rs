@@ -1,13 +1,12 @@
-use std::{
-    any::{Any, TypeId},
-    collections::HashMap,
-};
+use std::any::{Any, TypeId};
 
-use crate::caster::Caster;
+use crate::{
+    caster::{AnyCaster, Caster},
+    registry::CasterRegistry,
+};
 use linkme::distributed_slice;
-use once_cell::sync::Lazy;
 
-pub type BoxedTraitCaster = Box<dyn Any + Send + Sync>;
+pub type BoxedTraitCaster = Box<dyn AnyCaster>;
 
 pub type TargetId = TypeId;
 pub type CasterId = TypeId;
@@ -15,24 +14,23 @@ pub type CasterId = TypeId;
 #[distributed_slice]
 pub static TRAITCASTERS: [fn() -> (TargetId, BoxedTraitCaster)] = [..];
 
-static VTABLE: Lazy<HashMap<(TargetId, CasterId), BoxedTraitCaster>> = Lazy::new(|| {
-    TRAITCASTERS
-        .iter()
-        .map(|f| {
-            let (target_id, trait_caster) = f();
-            let trait_caster_id = (*trait_caster).type_id();
+pub(crate) fn get_trait_caster<T: ?Sized + Any>(target_id: TargetId) -> Option<&'static Caster<T>> {
+    CasterRegistry::global().get::<T>(target_id)
+}
 
-            ((target_id, trait_caster_id), trait_caster)
-        })
-        .collect::<HashMap<_, _>>()
-});
+/// Lists the names of the traits the type can be cast to
+pub fn implemented_traits(type_id: TypeId) -> Vec<&'static str> {
+    CasterRegistry::global().implemented_traits(type_id)
+}
 
-pub(crate) fn get_trait_caster<T: ?Sized + Any>(target_id: TargetId) -> Option<&'static Caster<T>> {
-    let trait_caster_id: CasterId = get_trait_caster_id::<T>();
+/// Lists the names of the traits the type can be cast to
+pub fn castable_to<S: Any>() -> Vec<&'static str> {
+    CasterRegistry::global().castable_to::<S>()
+}
 
-    VTABLE
-        .get(&(target_id, trait_caster_id))
-        .and_then(|trait_caster| trait_caster.downcast_ref::<Caster<T>>())
+/// Lists the names of the types that can be cast to the trait
+pub fn implementors_of<T: ?Sized + Any>() -> Vec<&'static str> {
+    CasterRegistry::global().implementors_of::<T>()
 }
 
 pub(crate) fn get_trait_caster_id<T: ?Sized + Any>() -> CasterId {
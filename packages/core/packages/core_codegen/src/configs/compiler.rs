@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream as TokenStream2;
+
+use super::parse::Config;
+
+pub(crate) fn gen_config_compiler(config: &Config) -> TokenStream2 {
+    let ident = &config.ident;
+    let path_to_lib = &config.attrs.path_to_lib;
+    let prefix = &config.attrs.prefix;
+
+    let validate_fn = match &config.attrs.validate {
+        Some(validate) => quote::quote! {
+            Some(|config: &#ident| #ident::#validate(config).map_err(|err| err.to_string()))
+        },
+        None => quote::quote! { None },
+    };
+
+    quote::quote! {
+      impl #path_to_lib::compiler::ProviderCompiler for #ident {
+        fn __blackbox_build(
+            provider_builder: #path_to_lib::cell::Ref<#path_to_lib::builder::ProviderBuilder>
+        ) {
+            #path_to_lib::modules::config::register_config::<#ident>(
+                provider_builder,
+                #prefix,
+                #validate_fn,
+            );
+        }
+      }
+
+      impl #path_to_lib::factory::Factory for #ident {
+        fn __blackbox_create(
+            _: &#path_to_lib::inquirer::Inquirer,
+        ) -> #path_to_lib::cell::Ref<Self> {
+            panic!("The {} config is loaded by the ConfigModule", ::std::any::type_name::<Self>())
+        }
+      }
+
+      #[#path_to_lib::implements]
+      #[blackbox_di(crate = #path_to_lib)]
+      impl #path_to_lib::injectable::IInjectable for #ident {}
+    }
+}
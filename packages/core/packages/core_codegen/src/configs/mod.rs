@@ -0,0 +1,5 @@
+mod compiler;
+mod parse;
+
+pub(crate) use compiler::gen_config_compiler;
+pub(crate) use parse::parse_config;
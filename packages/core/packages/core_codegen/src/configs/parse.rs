@@ -0,0 +1,71 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::{
+    parse, spanned::Spanned, AttributeArgs, Error, Ident, ItemStruct, Lit, Meta, NestedMeta,
+};
+
+use crate::helpers::get_path_to_lib;
+
+const PREFIX_IDENT: &str = "prefix";
+const VALIDATE_IDENT: &str = "validate";
+
+#[derive(Debug)]
+pub struct ConfigAttributes {
+    pub path_to_lib: TokenStream2,
+    pub prefix: String,
+    pub validate: Option<Ident>,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    pub ident: Ident,
+    pub attrs: ConfigAttributes,
+    source: ItemStruct,
+}
+
+impl Config {
+    pub fn to_token_stream(&self) -> TokenStream2 {
+        self.source.to_token_stream()
+    }
+}
+
+/// Parsing `#[config(prefix = "db", validate = "method")]`
+pub(crate) fn parse_config(args: AttributeArgs, input: TokenStream) -> Result<Config, Error> {
+    let mut item = parse::<ItemStruct>(input)?;
+
+    let mut attrs = ConfigAttributes {
+        path_to_lib: get_path_to_lib(&mut item.attrs)?,
+        prefix: String::new(),
+        validate: None,
+    };
+
+    for arg in args {
+        let name_value = match arg {
+            NestedMeta::Meta(Meta::NameValue(name_value)) => name_value,
+            arg => return Err(Error::new(arg.span(), "Expected `key = \"value\"`")),
+        };
+
+        let value = match &name_value.lit {
+            Lit::Str(value) => value.clone(),
+            lit => return Err(Error::new(lit.span(), "Expected string literal")),
+        };
+
+        if name_value.path.is_ident(PREFIX_IDENT) {
+            attrs.prefix = value.value();
+        } else if name_value.path.is_ident(VALIDATE_IDENT) {
+            attrs.validate = Some(value.parse::<Ident>()?);
+        } else {
+            return Err(Error::new(
+                name_value.path.span(),
+                "Unknown config attribute, expected `prefix` or `validate`",
+            ));
+        }
+    }
+
+    Ok(Config {
+        ident: item.ident.clone(),
+        attrs,
+        source: item,
+    })
+}
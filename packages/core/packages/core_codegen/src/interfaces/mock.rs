@@ -0,0 +1,232 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use syn::{
+    spanned::Spanned, Error, FnArg, ItemTrait, Pat, ReturnType, TraitItem, TraitItemMethod, Type,
+};
+
+struct MockedMethod {
+    method: TraitItemMethod,
+    field_ident: syn::Ident,
+    args_ty: TokenStream2,
+    args_value: TokenStream2,
+    return_ty: TokenStream2,
+    has_unit_return: bool,
+}
+
+fn parse_mocked_method(method: &TraitItemMethod) -> Result<MockedMethod, Error> {
+    let sig = &method.sig;
+
+    if sig.generics.type_params().next().is_some() || sig.generics.const_params().next().is_some() {
+        return Err(Error::new(
+            sig.generics.span(),
+            "#[interface(mock)] doesn't support generic methods",
+        ));
+    }
+
+    match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {}
+        _ => {
+            return Err(Error::new(
+                sig.span(),
+                "#[interface(mock)] supports only the methods with &self",
+            ))
+        }
+    }
+
+    let mut method = method.clone();
+    let mut arg_types: Vec<TokenStream2> = Vec::new();
+    let mut arg_values: Vec<TokenStream2> = Vec::new();
+
+    for (idx, arg) in method.sig.inputs.iter_mut().skip(1).enumerate() {
+        let pat_type = match arg {
+            FnArg::Typed(pat_type) => pat_type,
+            FnArg::Receiver(_) => unreachable!(),
+        };
+
+        let arg_ident = format_ident!("__arg{}", idx);
+
+        *pat_type.pat = Pat::Ident(syn::PatIdent {
+            attrs: Vec::new(),
+            by_ref: None,
+            mutability: None,
+            ident: arg_ident.clone(),
+            subpat: None,
+        });
+
+        // the referenced arguments are stored as owned values
+        match pat_type.ty.as_ref() {
+            Type::Reference(reference) => {
+                if reference.mutability.is_some() {
+                    return Err(Error::new(
+                        reference.span(),
+                        "#[interface(mock)] doesn't support &mut arguments",
+                    ));
+                }
+
+                let elem = &reference.elem;
+
+                arg_types.push(quote::quote! { <#elem as ::std::borrow::ToOwned>::Owned });
+                arg_values.push(quote::quote! { ::std::borrow::ToOwned::to_owned(#arg_ident) });
+            }
+            ty => {
+                arg_types.push(quote::quote! { #ty });
+                arg_values.push(quote::quote! { #arg_ident });
+            }
+        }
+    }
+
+    let (return_ty, has_unit_return) = match &sig.output {
+        ReturnType::Default => (quote::quote! { () }, true),
+        ReturnType::Type(_, ty) => {
+            if let Type::Reference(reference) = ty.as_ref() {
+                return Err(Error::new(
+                    reference.span(),
+                    "#[interface(mock)] doesn't support returning references",
+                ));
+            }
+
+            let is_unit = matches!(ty.as_ref(), Type::Tuple(tuple) if tuple.elems.is_empty());
+
+            (quote::quote! { #ty }, is_unit)
+        }
+    };
+
+    method.attrs.clear();
+    method.semi_token = None;
+
+    Ok(MockedMethod {
+        field_ident: format_ident!("__mock_{}", sig.ident),
+        args_ty: quote::quote! { (#(#arg_types,)*) },
+        args_value: quote::quote! { (#(#arg_values,)*) },
+        return_ty,
+        has_unit_return,
+        method,
+    })
+}
+
+/// Generates `Mock<Trait>` implementing the trait, the methods with a default body are not mocked
+pub(crate) fn gen_mock_code(
+    item: &ItemTrait,
+    path_to_lib: &TokenStream2,
+) -> Result<TokenStream2, Error> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "#[interface(mock)] doesn't support generic traits",
+        ));
+    }
+
+    let trait_ident = &item.ident;
+    let vis = &item.vis;
+    let mock_ident = format_ident!("Mock{}", trait_ident);
+
+    let methods = item
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Method(method) if method.default.is_none() => Some(method),
+            _ => None,
+        })
+        .map(parse_mocked_method)
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let fields = methods.iter().map(|method| {
+        let field_ident = &method.field_ident;
+        let args_ty = &method.args_ty;
+        let return_ty = &method.return_ty;
+
+        quote::quote! {
+            #field_ident: #path_to_lib::mock::MockMethod<#args_ty, #return_ty>
+        }
+    });
+
+    let field_inits = methods.iter().map(|method| {
+        let field_ident = &method.field_ident;
+        let name = format!("{}::{}", mock_ident, method.method.sig.ident);
+
+        if method.has_unit_return {
+            quote::quote! {
+                #field_ident: #path_to_lib::mock::MockMethod::new(#name).with_default(|_| ())
+            }
+        } else {
+            quote::quote! {
+                #field_ident: #path_to_lib::mock::MockMethod::new(#name)
+            }
+        }
+    });
+
+    let accessors = methods.iter().map(|method| {
+        let field_ident = &method.field_ident;
+        let args_ty = &method.args_ty;
+        let return_ty = &method.return_ty;
+        let expect_ident = format_ident!("expect_{}", method.method.sig.ident);
+        let calls_ident = format_ident!("{}_calls", method.method.sig.ident);
+
+        quote::quote! {
+            pub fn #expect_ident(&self) -> #path_to_lib::mock::Expectation<#args_ty, #return_ty> {
+                self.#field_ident.expect()
+            }
+
+            pub fn #calls_ident(&self) -> usize {
+                self.#field_ident.get_call_count()
+            }
+        }
+    });
+
+    let field_idents = methods.iter().map(|method| &method.field_ident);
+    let reset_field_idents = field_idents.clone();
+
+    let trait_methods = methods.iter().map(|method| {
+        let sig = &method.method.sig;
+        let field_ident = &method.field_ident;
+        let args_value = &method.args_value;
+
+        quote::quote! {
+            #sig {
+                self.#field_ident.call(#args_value)
+            }
+        }
+    });
+
+    Ok(quote::quote! {
+        #vis struct #mock_ident {
+            #(#fields),*
+        }
+
+        impl #mock_ident {
+            pub fn new() -> #mock_ident {
+                #mock_ident {
+                    #(#field_inits),*
+                }
+            }
+
+            #(#accessors)*
+
+            /// Panics if an expectation with `times` is not satisfied
+            pub fn verify(&self) {
+                #(self.#field_idents.verify();)*
+            }
+
+            /// Removes the expectations and resets the call counters
+            pub fn reset(&self) {
+                #(self.#reset_field_idents.reset();)*
+            }
+        }
+
+        impl ::std::default::Default for #mock_ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[#path_to_lib::implements]
+        #[blackbox_di(crate = #path_to_lib)]
+        impl #trait_ident for #mock_ident {
+            #(#trait_methods)*
+        }
+
+        #[#path_to_lib::implements]
+        #[blackbox_di(crate = #path_to_lib)]
+        impl #path_to_lib::injectable::IInjectable for #mock_ident {}
+    })
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "mock")]
+mod mock;
 mod parse;
 
 pub(crate) use parse::parse_interface;
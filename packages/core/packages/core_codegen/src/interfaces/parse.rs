@@ -1,12 +1,15 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{parse, Error, ItemTrait};
+use syn::{parse, parse::Parser, punctuated::Punctuated, Error, Ident, ItemTrait, Token};
 
 use crate::helpers::get_path_to_lib;
 
+const MOCK_IDENT: &str = "mock";
+
 #[derive(Debug)]
 pub struct InterfaceAttributes {
     pub path_to_lib: TokenStream2,
+    pub mock: bool,
 }
 
 #[derive(Debug)]
@@ -16,7 +19,7 @@ pub struct Interface {
 }
 
 impl Interface {
-    pub fn to_token_stream(&self) -> TokenStream2 {
+    pub fn to_token_stream(&self) -> Result<TokenStream2, Error> {
         let path_to_lib = &self.attrs.path_to_lib;
         let ItemTrait {
             ref ident,
@@ -28,21 +31,60 @@ impl Interface {
             ..
         } = self.source;
 
-        quote::quote! {
+        let mut result = quote::quote! {
             #[#path_to_lib::async_trait::async_trait]
             #vis #trait_token #ident #generics: #path_to_lib::blackbox_cast::CastFrom + #supertraits {
                 #(#items)*
             }
+        };
+
+        if self.attrs.mock {
+            result.extend(gen_mock_code(&self.source, path_to_lib)?);
         }
+
+        Ok(result)
     }
 }
 
-pub(crate) fn parse_interface(input: TokenStream) -> Result<Interface, Error> {
+#[cfg(feature = "mock")]
+fn gen_mock_code(item: &ItemTrait, path_to_lib: &TokenStream2) -> Result<TokenStream2, Error> {
+    super::mock::gen_mock_code(item, path_to_lib)
+}
+
+#[cfg(not(feature = "mock"))]
+fn gen_mock_code(item: &ItemTrait, _path_to_lib: &TokenStream2) -> Result<TokenStream2, Error> {
+    Err(Error::new(
+        item.ident.span(),
+        "#[interface(mock)] requires the `mock` feature",
+    ))
+}
+
+/// #[interface(mock)]
+fn parse_interface_args(args: TokenStream) -> Result<bool, Error> {
+    let idents = Punctuated::<Ident, Token![,]>::parse_terminated.parse(args)?;
+    let mut mock = false;
+
+    for ident in idents {
+        if ident == MOCK_IDENT {
+            mock = true;
+        } else {
+            return Err(Error::new(
+                ident.span(),
+                format!("Unknown {} interface option", ident),
+            ));
+        }
+    }
+
+    Ok(mock)
+}
+
+pub(crate) fn parse_interface(args: TokenStream, input: TokenStream) -> Result<Interface, Error> {
     let mut item = parse::<ItemTrait>(input.clone())?;
 
     let path_to_lib = get_path_to_lib(&mut item.attrs)?;
+    let mock = parse_interface_args(args)?;
 
-    let attrs = InterfaceAttributes { path_to_lib };
+    let attrs = InterfaceAttributes { path_to_lib, mock };
 
     let interface = Interface {
         source: item,
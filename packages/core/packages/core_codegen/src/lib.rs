@@ -1,15 +1,20 @@
+mod configs;
 mod helpers;
 mod interfaces;
 mod modules;
 mod providers;
 
+use configs::{gen_config_compiler, parse_config};
 use helpers::get_path_to_lib;
 use interfaces::parse_interface;
 use modules::{gen_module_compiler, parse_module};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use providers::{gen_factory_code, gen_provider_compiler, parse_provider};
-use syn::{parse, ItemFn};
+use providers::{
+    gen_event_handlers_code, gen_factory_code, gen_interception_code, gen_methods_metadata_code,
+    gen_provider_compiler, gen_scheduled_jobs_code, parse_provider,
+};
+use syn::{parse, parse_macro_input, AttributeArgs, ItemFn};
 
 #[proc_macro_attribute]
 pub fn injectable(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -38,18 +43,67 @@ pub fn module(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_attribute]
-pub fn interface(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let interface = parse_interface(item).unwrap();
+pub fn config(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
 
-    interface.to_token_stream().into()
+    let config = match parse_config(args, item) {
+        Ok(config) => config,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut result = config.to_token_stream();
+
+    result.extend(gen_config_compiler(&config));
+
+    result.into()
+}
+
+#[proc_macro_attribute]
+pub fn interface(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let result = parse_interface(attr, item).and_then(|interface| interface.to_token_stream());
+
+    match result {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
 }
 
 #[proc_macro_attribute]
 pub fn implements(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let provider = parse_provider(item).expect("#[implements] can be used only on impl blocks");
+    let provider = match parse_provider(item) {
+        Ok(provider) => provider,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
     let mut result = TokenStream2::new();
 
+    result.extend(gen_methods_metadata_code(
+        &provider.self_ty,
+        &provider.methods_metadata,
+        &provider.attrs.path_to_lib,
+    ));
+
+    result.extend(gen_event_handlers_code(
+        &provider.self_ty,
+        &provider.interface,
+        &provider.event_handlers,
+        &provider.attrs.path_to_lib,
+    ));
+
+    result.extend(gen_scheduled_jobs_code(
+        &provider.self_ty,
+        &provider.interface,
+        &provider.scheduled_methods,
+        &provider.attrs.path_to_lib,
+    ));
+
+    if let Some(interception) = &provider.interception {
+        result.extend(gen_interception_code(
+            interception,
+            &provider.attrs.path_to_lib,
+        ));
+    }
+
     if provider.has_interface() {
         let item = provider.to_token_stream();
         let path_to_lib = &provider.attrs.path_to_lib;
@@ -28,6 +28,13 @@ pub(crate) fn gen_provider_compiler(provider: &Provider) -> TokenStream2 {
     attrs.push(quote::quote! { set_scope(#scope) });
     attrs.append(&mut register_deps);
 
+    provider.attrs.metadata.iter().for_each(|entry| {
+        let key = &entry.key;
+        let value = &entry.value;
+
+        attrs.push(quote::quote! { set_metadata(#key, #value) });
+    });
+
     let register_attrs = if attrs.len() != 0 {
         quote::quote! { provider_builder #(.#attrs)*; }
     } else {
@@ -1,5 +1,8 @@
 use proc_macro2::TokenStream as TokenStream2;
-use syn::{Ident, Path};
+use syn::{Ident, Path, PathArguments};
+
+const INQUIRER_IDENT: &str = "Inquirer";
+const INQUIRER_MODULE_IDENT: &str = "inquirer";
 
 #[derive(Debug)]
 pub struct Inject {
@@ -17,6 +20,7 @@ pub struct ProviderDependency {
     pub ident: Ident,
     pub path: Path,
     pub inject: Option<Inject>,
+    pub inquirer: bool,
 }
 
 impl ProviderDependency {
@@ -25,10 +29,53 @@ impl ProviderDependency {
             ident,
             path,
             inject,
+            inquirer: false,
         }
     }
 
     pub fn is_injectable(&self) -> bool {
         self.inject.is_some()
     }
+
+    /// The `Inquirer` dependency is passed to the factory instead of being injected
+    pub fn is_inquirer(&self) -> bool {
+        self.inquirer
+    }
+}
+
+/// Checks the path is the fully qualified `Inquirer` path of the lib,
+/// e.g. `blackbox_di::Inquirer` or `blackbox_di::inquirer::Inquirer`
+pub(crate) fn is_inquirer_path(path: &Path, path_to_lib: &TokenStream2) -> bool {
+    let lib_path = match syn::parse2::<Path>(path_to_lib.clone()) {
+        Ok(lib_path) => lib_path,
+        Err(_) => return false,
+    };
+
+    if path
+        .segments
+        .iter()
+        .any(|segment| segment.arguments != PathArguments::None)
+    {
+        return false;
+    }
+
+    let get_idents = |path: &Path| {
+        path.segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let lib_idents = get_idents(&lib_path);
+    let idents = get_idents(path);
+
+    if idents.len() <= lib_idents.len() || idents[..lib_idents.len()] != lib_idents[..] {
+        return false;
+    }
+
+    match &idents[lib_idents.len()..] {
+        [ident] => ident == INQUIRER_IDENT,
+        [module, ident] => module == INQUIRER_MODULE_IDENT && ident == INQUIRER_IDENT,
+        _ => false,
+    }
 }
@@ -36,7 +36,9 @@ impl ProviderDependencyCollection {
     }
 
     pub fn has_uninjectable_deps(&self) -> bool {
-        self.0.iter().any(|dependency| !dependency.is_injectable())
+        self.0
+            .iter()
+            .any(|dependency| !dependency.is_injectable() && !dependency.is_inquirer())
     }
 
     pub fn iter(&self) -> std::slice::Iter<'_, ProviderDependency> {
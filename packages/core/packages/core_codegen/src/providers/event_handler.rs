@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::Result, spanned::Spanned, Attribute, Error, FnArg, Ident, ImplItemMethod, LitStr, Path,
+    Type,
+};
+
+const ON_EVENT_IDENT: &str = "on_event";
+
+#[derive(Debug)]
+pub struct EventHandlerMethod {
+    pub ident: Ident,
+    pub topics: Vec<LitStr>,
+    pub payload: Option<Type>,
+    pub is_async: bool,
+}
+
+/// Collecting the `#[on_event("topic")]` attributes of the method
+///
+/// Supported signatures: `fn(&self)` and `fn(&self, payload: &P)`, sync or async,
+/// returning either `()` or `Result<(), E>`
+pub(crate) fn get_event_handler(method: &mut ImplItemMethod) -> Result<Option<EventHandlerMethod>> {
+    let topics = get_event_topics(&mut method.attrs)?;
+
+    if topics.is_empty() {
+        return Ok(None);
+    }
+
+    let mut inputs = method.sig.inputs.iter();
+
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() => {}
+        _ => {
+            return Err(Error::new(
+                method.sig.span(),
+                "#[on_event] handler must take &self",
+            ))
+        }
+    }
+
+    let payload = match inputs.next() {
+        Some(FnArg::Typed(arg)) => match &*arg.ty {
+            Type::Reference(reference) => Some(*reference.elem.clone()),
+            ty => return Err(Error::new(ty.span(), "Expected payload reference, e.g. &P")),
+        },
+        _ => None,
+    };
+
+    if let Some(arg) = inputs.next() {
+        return Err(Error::new(
+            arg.span(),
+            "#[on_event] handler takes only the payload argument",
+        ));
+    }
+
+    Ok(Some(EventHandlerMethod {
+        ident: method.sig.ident.clone(),
+        topics,
+        payload,
+        is_async: method.sig.asyncness.is_some(),
+    }))
+}
+
+fn get_event_topics(attrs: &mut Vec<Attribute>) -> Result<Vec<LitStr>> {
+    let mut topics: Vec<LitStr> = Vec::new();
+    let mut error: Option<Error> = None;
+
+    attrs.retain(|attr| {
+        if !attr.path.is_ident(ON_EVENT_IDENT) {
+            return true;
+        }
+
+        match attr.parse_args::<LitStr>() {
+            Ok(topic) => topics.push(topic),
+            Err(_) => error = Some(Error::new(attr.span(), "Expected #[on_event(\"topic\")]")),
+        }
+
+        false
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(topics),
+    }
+}
+
+pub(crate) fn gen_event_handlers_code(
+    self_ty: &TokenStream2,
+    interface: &Option<Path>,
+    event_handlers: &[EventHandlerMethod],
+    path_to_lib: &TokenStream2,
+) -> TokenStream2 {
+    let events_path = &quote::quote! { #path_to_lib::modules::event_emitter };
+
+    let method_prefix = &match interface {
+        Some(interface) => quote::quote! { <#self_ty as #interface> },
+        None => quote::quote! { <#self_ty> },
+    };
+
+    let records = event_handlers.iter().flat_map(|event_handler| {
+        let ident = &event_handler.ident;
+        let method = ident.to_string();
+
+        let (get_payload, args) = match &event_handler.payload {
+            Some(payload) => (
+                quote::quote! {
+                    let payload = match event.get_payload::<#payload>() {
+                        Some(payload) => payload,
+                        None => return Ok(()),
+                    };
+                },
+                quote::quote! { &provider, &payload },
+            ),
+            None => (quote::quote! {}, quote::quote! { &provider }),
+        };
+
+        let call = if event_handler.is_async {
+            quote::quote! { #method_prefix::#ident(#args).await }
+        } else {
+            quote::quote! { #method_prefix::#ident(#args) }
+        };
+
+        event_handler.topics.iter().map(move |topic| {
+            quote::quote! {
+                const _: () = {
+                    #[#path_to_lib::blackbox_cast::linkme::distributed_slice(#events_path::EVENT_HANDLERS)]
+                    #[linkme(crate = #path_to_lib::blackbox_cast::linkme)]
+                    fn __blackbox_event_handler() -> #events_path::EventHandlerRecord {
+                        #events_path::EventHandlerRecord {
+                            provider_id: ::std::any::TypeId::of::<#self_ty>(),
+                            provider: ::std::any::type_name::<#self_ty>(),
+                            method: #method,
+                            topic: #topic,
+                            handler: |instance, event| {
+                                Box::pin(async move {
+                                    let provider = instance
+                                        .cast::<#self_ty>()
+                                        .map_err(|err| err.to_string())?
+                                        .as_ref();
+
+                                    #get_payload
+
+                                    #events_path::IntoEventResult::into_event_result(#call)
+                                })
+                            },
+                        }
+                    }
+                };
+            }
+        })
+    });
+
+    quote::quote! {
+        #(#records)*
+    }
+}
@@ -57,7 +57,10 @@ pub(crate) fn gen_factory_code(provider: &Provider) -> TokenStream2 {
 
     quote::quote! {
         impl #path_to_lib::factory::Factory for #provider_ident {
-            fn __blackbox_create() -> #path_to_lib::cell::Ref<#provider_ident> {
+            #[allow(unused_variables)]
+            fn __blackbox_create(
+                __inquirer: &#path_to_lib::inquirer::Inquirer,
+            ) -> #path_to_lib::cell::Ref<#provider_ident> {
                 #inject_deps_code
 
                 #path_to_lib::cell::Ref::new(#factory_ident #factory_code)
@@ -33,6 +33,12 @@ pub(crate) fn gen_inject_dep_code(
     let ident = gen_dep_ident(uident);
     let path_to_lib = &attrs.path_to_lib;
 
+    if dep.is_inquirer() {
+        return quote::quote! {
+            let #ident = ::std::clone::Clone::clone(__inquirer);
+        };
+    }
+
     quote::quote! {
         let #ident = #path_to_lib::cell::Ref::<#ref_path>::empty();
     }
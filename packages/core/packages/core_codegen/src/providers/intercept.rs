@@ -0,0 +1,355 @@
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, ToTokens};
+use syn::{
+    parse::Result, punctuated::Punctuated, spanned::Spanned, Attribute, Error, FnArg, GenericParam,
+    Generics, Ident, ImplItem, ImplItemMethod, ItemImpl, Pat, PatIdent, Path, ReturnType, Token,
+    Type,
+};
+
+const INTERCEPT_IDENT: &str = "intercept";
+const INTERCEPTED_METHOD_PREFIX: &str = "__blackbox_intercepted_";
+
+#[derive(Debug)]
+pub struct Interception {
+    pub generics: Generics,
+    pub self_ty: Type,
+    pub interceptors: Vec<Path>,
+    pub methods: Vec<ImplItemMethod>,
+}
+
+impl Interception {
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
+
+/// Collecting the `#[intercept(Interceptor, ...)]` attributes
+fn get_interceptors(attrs: &mut Vec<Attribute>) -> Result<Vec<Path>> {
+    let mut interceptors: Vec<Path> = Vec::new();
+    let mut error: Option<Error> = None;
+
+    attrs.retain(|attr| {
+        if !attr.path.is_ident(INTERCEPT_IDENT) {
+            return true;
+        }
+
+        match attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated) {
+            Ok(paths) => interceptors.extend(paths),
+            Err(err) => error = Some(err),
+        }
+
+        false
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(interceptors),
+    }
+}
+
+/// Wraps the methods of the impl block marked with `#[intercept]` or placed in the marked block.
+///
+/// The original body is moved to the hidden inherent method, block interceptors go first.
+pub(crate) fn intercept_methods(
+    impl_block: &mut ItemImpl,
+    path_to_lib: &TokenStream2,
+) -> Result<Interception> {
+    let block_interceptors = get_interceptors(&mut impl_block.attrs)?;
+
+    let interface = impl_block
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .map(|segment| segment.ident.to_string());
+
+    let mut interception = Interception {
+        generics: impl_block.generics.clone(),
+        self_ty: *impl_block.self_ty.clone(),
+        interceptors: Vec::new(),
+        methods: Vec::new(),
+    };
+
+    for item in impl_block.items.iter_mut() {
+        let method = match item {
+            ImplItem::Method(method) => method,
+            _ => continue,
+        };
+
+        let method_interceptors = get_interceptors(&mut method.attrs)?;
+
+        let interceptors = block_interceptors
+            .iter()
+            .chain(method_interceptors.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if interceptors.is_empty() {
+            continue;
+        }
+
+        // the block interceptors skip such methods
+        if let Some((span, reason)) = get_not_interceptable_reason(method) {
+            if method_interceptors.is_empty() {
+                continue;
+            }
+
+            return Err(Error::new(
+                span,
+                format!(
+                    "The {} method can't be intercepted: {}",
+                    method.sig.ident, reason
+                ),
+            ));
+        }
+
+        let intercepted_method = wrap_method(method, &interceptors, &interface, path_to_lib);
+
+        interception.methods.push(intercepted_method);
+
+        for interceptor in interceptors {
+            if !interception.interceptors.contains(&interceptor) {
+                interception.interceptors.push(interceptor);
+            }
+        }
+    }
+
+    Ok(interception)
+}
+
+/// The arguments are cloned for every `next` call and the result is boxed as `Any`, so it must be `'static`
+fn get_not_interceptable_reason(method: &ImplItemMethod) -> Option<(Span, &'static str)> {
+    let sig = &method.sig;
+
+    if !has_ref_receiver(method) {
+        return Some((sig.span(), "only the methods taking &self are supported"));
+    }
+
+    if has_type_params(&sig.generics) {
+        return Some((sig.generics.span(), "generic methods are not supported"));
+    }
+
+    for input in sig.inputs.iter().skip(1) {
+        let ty = match input {
+            FnArg::Typed(pat_type) => &pat_type.ty,
+            FnArg::Receiver(_) => continue,
+        };
+
+        let tokens = ty.to_token_stream();
+
+        if has_ident(&tokens, "impl") {
+            return Some((ty.span(), "impl Trait arguments are not supported"));
+        }
+
+        if has_mut_ref(&tokens) {
+            return Some((
+                ty.span(),
+                "&mut arguments can't be cloned for the interceptors",
+            ));
+        }
+    }
+
+    if let ReturnType::Type(_, ty) = &sig.output {
+        let tokens = ty.to_token_stream();
+
+        if has_ident(&tokens, "impl") || has_borrow(&tokens) {
+            return Some((
+                ty.span(),
+                "the return type must be 'static, borrowed values and impl Trait are not supported",
+            ));
+        }
+    }
+
+    None
+}
+
+fn has_ident(tokens: &TokenStream2, ident: &str) -> bool {
+    tokens.clone().into_iter().any(|token| match token {
+        TokenTree::Ident(token) => token == ident,
+        TokenTree::Group(group) => has_ident(&group.stream(), ident),
+        _ => false,
+    })
+}
+
+fn has_mut_ref(tokens: &TokenStream2) -> bool {
+    let tokens = tokens.clone().into_iter().collect::<Vec<_>>();
+
+    tokens.iter().enumerate().any(|(idx, token)| match token {
+        TokenTree::Punct(punct) if punct.as_char() == '&' => {
+            matches!(tokens.get(idx + 1), Some(TokenTree::Ident(ident)) if ident == "mut")
+        }
+        TokenTree::Group(group) => has_mut_ref(&group.stream()),
+        _ => false,
+    })
+}
+
+/// References and lifetimes except `'static`
+fn has_borrow(tokens: &TokenStream2) -> bool {
+    let tokens = tokens.clone().into_iter().collect::<Vec<_>>();
+
+    tokens.iter().enumerate().any(|(idx, token)| match token {
+        TokenTree::Punct(punct) if punct.as_char() == '&' => !matches!(
+            (tokens.get(idx + 1), tokens.get(idx + 2)),
+            (Some(TokenTree::Punct(tick)), Some(TokenTree::Ident(ident))) if tick.as_char() == '\'' && ident == "static"
+        ),
+        TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+            !matches!(tokens.get(idx + 1), Some(TokenTree::Ident(ident)) if ident == "static")
+        }
+        TokenTree::Group(group) => has_borrow(&group.stream()),
+        _ => false,
+    })
+}
+
+fn has_ref_receiver(method: &ImplItemMethod) -> bool {
+    matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    )
+}
+
+fn has_type_params(generics: &Generics) -> bool {
+    generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, GenericParam::Lifetime(_)))
+}
+
+/// Replaces the body of the method by the interceptors call and returns the method with the original body
+fn wrap_method(
+    method: &mut ImplItemMethod,
+    interceptors: &[Path],
+    interface: &Option<String>,
+    path_to_lib: &TokenStream2,
+) -> ImplItemMethod {
+    let method_name = method.sig.ident.to_string();
+
+    let mut intercepted_method = method.clone();
+
+    intercepted_method.sig.ident = format_ident!("{}{}", INTERCEPTED_METHOD_PREFIX, method_name);
+    intercepted_method.vis = syn::Visibility::Inherited;
+    intercepted_method.attrs = vec![syn::parse_quote! { #[doc(hidden)] }];
+
+    let intercepted_ident = &intercepted_method.sig.ident;
+
+    let interceptor_path = quote::quote! { #path_to_lib::interceptor };
+
+    let mut args: Vec<Ident> = Vec::new();
+    // the non-Clone arguments are reported at their types
+    let mut arg_clones: Vec<TokenStream2> = Vec::new();
+
+    for (idx, input) in method.sig.inputs.iter_mut().skip(1).enumerate() {
+        let ident = format_ident!("__arg{}", idx);
+
+        if let FnArg::Typed(pat_type) = input {
+            *pat_type.pat = Pat::Ident(PatIdent {
+                attrs: Vec::new(),
+                by_ref: None,
+                mutability: None,
+                ident: ident.clone(),
+                subpat: None,
+            });
+
+            let span = pat_type.ty.span();
+            let spanned_ident = Ident::new(&ident.to_string(), span);
+
+            arg_clones.push(quote::quote_spanned! { span =>
+                #interceptor_path::clone_arg(&#spanned_ident)
+            });
+        }
+
+        args.push(ident);
+    }
+
+    let return_ty = match &method.sig.output {
+        ReturnType::Default => quote::quote! { () },
+        ReturnType::Type(_, ty) => quote::quote! { #ty },
+    };
+
+    let interface = match interface {
+        Some(interface) => quote::quote! { Some(#interface) },
+        None => quote::quote! { None },
+    };
+
+    let call = quote::quote! {
+        let __call = #interceptor_path::InterceptedCall {
+            interface: #interface,
+            method: #method_name,
+            interceptors: vec![#(::std::any::TypeId::of::<#interceptors>()),*],
+        };
+    };
+
+    let body = if method.sig.asyncness.is_some() {
+        quote::quote! {
+            {
+                #call
+
+                let __next = #interceptor_path::async_next(|| {
+                    #(let #args = #arg_clones;)*
+
+                    Box::pin(async move {
+                        Box::new(self.#intercepted_ident(#(#args),*).await) as #interceptor_path::CallResult
+                    })
+                });
+
+                *#interceptor_path::intercept_async_call(self, &__call, &__next)
+                    .await
+                    .downcast::<#return_ty>()
+                    .expect("The interceptor returned a value of another type")
+            }
+        }
+    } else {
+        quote::quote! {
+            {
+                #call
+
+                let __next = || -> #interceptor_path::CallResult {
+                    Box::new(self.#intercepted_ident(#(#arg_clones),*))
+                };
+
+                *#interceptor_path::intercept_call(self, &__call, &__next)
+                    .downcast::<#return_ty>()
+                    .expect("The interceptor returned a value of another type")
+            }
+        }
+    };
+
+    method.block = syn::parse2(body).unwrap();
+
+    intercepted_method
+}
+
+pub(crate) fn gen_interception_code(
+    interception: &Interception,
+    path_to_lib: &TokenStream2,
+) -> TokenStream2 {
+    if interception.is_empty() {
+        return quote::quote! {};
+    }
+
+    let self_ty = &interception.self_ty;
+    let methods = &interception.methods;
+    let (impl_generics, _, where_clause) = interception.generics.split_for_impl();
+
+    let records = interception.interceptors.iter().map(|interceptor| {
+        quote::quote! {
+            const _: () = {
+                #[#path_to_lib::blackbox_cast::linkme::distributed_slice(#path_to_lib::interceptor::INTERCEPTORS)]
+                #[linkme(crate = #path_to_lib::blackbox_cast::linkme)]
+                fn __blackbox_interceptor() -> #path_to_lib::interceptor::InterceptorRecord {
+                    #path_to_lib::interceptor::InterceptorRecord {
+                        provider_id: ::std::any::TypeId::of::<#self_ty>(),
+                        interceptor_id: ::std::any::TypeId::of::<#interceptor>(),
+                        interceptor_token: #path_to_lib::tokens::get_token::<#interceptor>(),
+                    }
+                }
+            };
+        }
+    });
+
+    quote::quote! {
+        impl #impl_generics #self_ty #where_clause {
+            #(#methods)*
+        }
+
+        #(#records)*
+    }
+}
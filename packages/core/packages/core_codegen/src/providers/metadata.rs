@@ -0,0 +1,122 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse::Result, spanned::Spanned, Attribute, Error, Ident, Lit, Meta, NestedMeta};
+
+const METADATA_IDENT: &str = "metadata";
+
+#[derive(Debug)]
+pub struct MetadataEntry {
+    pub key: String,
+    pub value: TokenStream2,
+}
+
+#[derive(Debug)]
+pub struct MethodMetadata {
+    pub ident: Ident,
+    pub entries: Vec<MetadataEntry>,
+}
+
+/// Collecting the `#[metadata(key = value, ...)]` attributes
+///
+/// Supported values: string, integer, float and bool literals
+pub(crate) fn get_metadata(
+    attrs: &mut Vec<Attribute>,
+    path_to_lib: &TokenStream2,
+) -> Result<Vec<MetadataEntry>> {
+    let mut entries: Vec<MetadataEntry> = Vec::new();
+    let mut error: Option<Error> = None;
+
+    attrs.retain(|attr| {
+        if !attr.path.is_ident(METADATA_IDENT) {
+            return true;
+        }
+
+        match parse_metadata_attr(attr, path_to_lib) {
+            Ok(mut attr_entries) => entries.append(&mut attr_entries),
+            Err(err) => error = Some(err),
+        }
+
+        false
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(entries),
+    }
+}
+
+fn parse_metadata_attr(attr: &Attribute, path_to_lib: &TokenStream2) -> Result<Vec<MetadataEntry>> {
+    let meta_list = match attr.parse_meta()? {
+        Meta::List(meta_list) => meta_list,
+        meta => {
+            return Err(Error::new(
+                meta.span(),
+                "Expected #[metadata(key = value, ...)]",
+            ))
+        }
+    };
+
+    meta_list
+        .nested
+        .iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                let key = name_value
+                    .path
+                    .get_ident()
+                    .ok_or_else(|| Error::new(name_value.path.span(), "Expected metadata key"))?
+                    .to_string();
+
+                let value = gen_metadata_value(&name_value.lit, path_to_lib)?;
+
+                Ok(MetadataEntry { key, value })
+            }
+            nested => Err(Error::new(nested.span(), "Expected `key = value`")),
+        })
+        .collect()
+}
+
+fn gen_metadata_value(lit: &Lit, path_to_lib: &TokenStream2) -> Result<TokenStream2> {
+    let value_path = quote::quote! { #path_to_lib::metadata::MetadataValue };
+
+    match lit {
+        Lit::Str(value) => Ok(quote::quote! { #value_path::String(String::from(#value)) }),
+        Lit::Int(value) => Ok(quote::quote! { #value_path::Int(#value as i64) }),
+        Lit::Float(value) => Ok(quote::quote! { #value_path::Float(#value as f64) }),
+        Lit::Bool(value) => Ok(quote::quote! { #value_path::Bool(#value) }),
+        lit => Err(Error::new(lit.span(), "Unsupported metadata value")),
+    }
+}
+
+pub(crate) fn gen_methods_metadata_code(
+    self_ty: &TokenStream2,
+    methods_metadata: &[MethodMetadata],
+    path_to_lib: &TokenStream2,
+) -> TokenStream2 {
+    let records = methods_metadata.iter().map(|method_metadata| {
+        let method = method_metadata.ident.to_string();
+        let keys = method_metadata.entries.iter().map(|entry| &entry.key);
+        let values = method_metadata.entries.iter().map(|entry| &entry.value);
+
+        quote::quote! {
+            const _: () = {
+                #[#path_to_lib::blackbox_cast::linkme::distributed_slice(#path_to_lib::metadata::METHODS_METADATA)]
+                #[linkme(crate = #path_to_lib::blackbox_cast::linkme)]
+                fn __blackbox_method_metadata() -> #path_to_lib::metadata::MethodMetadataRecord {
+                    let mut metadata = #path_to_lib::metadata::Metadata::new();
+
+                    #(metadata.set(#keys, #values);)*
+
+                    #path_to_lib::metadata::MethodMetadataRecord {
+                        provider_id: ::std::any::TypeId::of::<#self_ty>(),
+                        method: #method,
+                        metadata,
+                    }
+                }
+            };
+        }
+    });
+
+    quote::quote! {
+        #(#records)*
+    }
+}
@@ -1,12 +1,20 @@
 mod compiler;
 mod dependency;
 mod dependency_collection;
+mod event_handler;
 mod factory;
 mod helpers;
 mod inject;
+mod intercept;
+mod metadata;
 mod parse;
+mod schedule;
 mod scope;
 
 pub(crate) use compiler::gen_provider_compiler;
+pub(crate) use event_handler::gen_event_handlers_code;
 pub(crate) use factory::gen_factory_code;
+pub(crate) use intercept::gen_interception_code;
+pub(crate) use metadata::gen_methods_metadata_code;
 pub(crate) use parse::parse_provider;
+pub(crate) use schedule::gen_scheduled_jobs_code;
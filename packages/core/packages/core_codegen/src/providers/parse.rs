@@ -9,24 +9,34 @@ use syn::{
 use crate::helpers::{get_path_from_type, get_path_to_lib, get_ref_path};
 
 use super::{
-    dependency::{Inject, ProviderDependency},
+    dependency::{is_inquirer_path, Inject, ProviderDependency},
     dependency_collection::ProviderDependencyCollection,
+    event_handler::{get_event_handler, EventHandlerMethod},
     helpers::{gen_dep_ident, get_provider_ident_from_impl_block},
+    intercept::{intercept_methods, Interception},
+    metadata::{get_metadata, MetadataEntry, MethodMetadata},
+    schedule::{get_scheduled_method, ScheduledMethod},
     scope::{get_scope, Scope},
 };
 
 const INJECT_IDENT: &str = "inject";
+const INQUIRER_IDENT: &str = "inquirer";
 const FACTORY_IDENT: &str = "factory";
 
 #[derive(Debug)]
 pub struct ProviderAttributes {
     pub path_to_lib: TokenStream2,
     pub scope: Scope,
+    pub metadata: Vec<MetadataEntry>,
 }
 
 impl ProviderAttributes {
     fn new(path_to_lib: TokenStream2, scope: Scope) -> ProviderAttributes {
-        ProviderAttributes { path_to_lib, scope }
+        ProviderAttributes {
+            path_to_lib,
+            scope,
+            metadata: Vec::new(),
+        }
     }
 }
 
@@ -38,6 +48,11 @@ pub struct Provider {
     pub factory_ident: Option<TokenStream2>,
     pub interface: Option<Path>,
     pub attrs: ProviderAttributes,
+    pub self_ty: TokenStream2,
+    pub methods_metadata: Vec<MethodMetadata>,
+    pub event_handlers: Vec<EventHandlerMethod>,
+    pub scheduled_methods: Vec<ScheduledMethod>,
+    pub interception: Option<Interception>,
     source: TokenStream2,
 }
 
@@ -63,11 +78,13 @@ pub(crate) fn parse_provider(input: TokenStream) -> Result<Provider, Error> {
     if let Ok(item) = parse::<ItemStruct>(input.clone()) {
         let mut item = item;
 
-        let attrs = ProviderAttributes::new(
+        let mut attrs = ProviderAttributes::new(
             get_path_to_lib(&mut item.attrs)?,
             get_scope(&mut item.attrs)?,
         );
 
+        attrs.metadata = get_metadata(&mut item.attrs, &attrs.path_to_lib)?;
+
         let deps = parse_provider_deps_by_struct(&mut item, &attrs);
 
         let provider = Provider {
@@ -77,6 +94,11 @@ pub(crate) fn parse_provider(input: TokenStream) -> Result<Provider, Error> {
             attrs,
             factory_ident: None,
             interface: None,
+            self_ty: item.ident.to_token_stream(),
+            methods_metadata: Vec::new(),
+            event_handlers: Vec::new(),
+            scheduled_methods: Vec::new(),
+            interception: None,
             source: item.to_token_stream(),
         };
 
@@ -90,6 +112,11 @@ pub(crate) fn parse_provider(input: TokenStream) -> Result<Provider, Error> {
             get_scope(&mut item.attrs)?,
         );
 
+        let methods_metadata = parse_methods_metadata(&mut item, &attrs)?;
+        let event_handlers = parse_event_handlers(&mut item)?;
+        let scheduled_methods = parse_scheduled_methods(&mut item)?;
+        let interception = intercept_methods(&mut item, &attrs.path_to_lib)?;
+
         let factory_fn = detect_factory_method(&mut item);
         let mut factory_ident: Option<TokenStream2> = None;
 
@@ -99,7 +126,7 @@ pub(crate) fn parse_provider(input: TokenStream) -> Result<Provider, Error> {
 
             factory_ident = Some(quote::quote! { #fn_prefix #fn_ident });
 
-            parse_provider_deps_by_fn(&factory_fn)
+            parse_provider_deps_by_fn(&factory_fn, &attrs)
         } else {
             ProviderDependencyCollection::new()
         };
@@ -117,6 +144,11 @@ pub(crate) fn parse_provider(input: TokenStream) -> Result<Provider, Error> {
             attrs,
             factory_ident,
             interface,
+            self_ty: item.self_ty.to_token_stream(),
+            methods_metadata,
+            event_handlers,
+            scheduled_methods,
+            interception: Some(interception),
             source: item.to_token_stream(),
         };
 
@@ -141,7 +173,16 @@ fn parse_provider_deps_by_struct(
             let mut provider_dep =
                 ProviderDependency::new(dep_ident.clone(), dep_path.clone(), None);
 
+            provider_dep.inquirer = is_inquirer_path(&dep_path, path_to_lib);
+
             field.attrs.retain(|attr| {
+                // #[inquirer]
+                if attr.path.is_ident(INQUIRER_IDENT) {
+                    provider_dep.inquirer = true;
+
+                    return false;
+                }
+
                 if !attr.path.is_ident(INJECT_IDENT) {
                     return true;
                 }
@@ -188,7 +229,10 @@ fn parse_provider_deps_by_struct(
         .collect::<ProviderDependencyCollection>()
 }
 
-fn parse_provider_deps_by_fn(item: &ItemFn) -> ProviderDependencyCollection {
+fn parse_provider_deps_by_fn(
+    item: &ItemFn,
+    attrs: &ProviderAttributes,
+) -> ProviderDependencyCollection {
     item.sig
         .inputs
         .iter()
@@ -203,13 +247,72 @@ fn parse_provider_deps_by_fn(item: &ItemFn) -> ProviderDependencyCollection {
             let dep_ident = gen_dep_ident(idx);
             let dep_path = get_path_from_type(&typed.ty).unwrap();
 
-            let provider_field = ProviderDependency::new(dep_ident.clone(), dep_path.clone(), None);
+            let mut provider_field =
+                ProviderDependency::new(dep_ident.clone(), dep_path.clone(), None);
+
+            provider_field.inquirer = is_inquirer_path(&dep_path, &attrs.path_to_lib)
+                || typed
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path.is_ident(INQUIRER_IDENT));
 
             provider_field
         })
         .collect::<ProviderDependencyCollection>()
 }
 
+fn parse_methods_metadata(
+    impl_block: &mut ItemImpl,
+    attrs: &ProviderAttributes,
+) -> Result<Vec<MethodMetadata>, Error> {
+    let mut methods_metadata: Vec<MethodMetadata> = Vec::new();
+
+    for item in impl_block.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            let entries = get_metadata(&mut method.attrs, &attrs.path_to_lib)?;
+
+            if entries.is_empty() {
+                continue;
+            }
+
+            methods_metadata.push(MethodMetadata {
+                ident: method.sig.ident.clone(),
+                entries,
+            });
+        }
+    }
+
+    Ok(methods_metadata)
+}
+
+fn parse_event_handlers(impl_block: &mut ItemImpl) -> Result<Vec<EventHandlerMethod>, Error> {
+    let mut event_handlers: Vec<EventHandlerMethod> = Vec::new();
+
+    for item in impl_block.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            if let Some(event_handler) = get_event_handler(method)? {
+                event_handlers.push(event_handler);
+            }
+        }
+    }
+
+    Ok(event_handlers)
+}
+
+fn parse_scheduled_methods(impl_block: &mut ItemImpl) -> Result<Vec<ScheduledMethod>, Error> {
+    let mut scheduled_methods: Vec<ScheduledMethod> = Vec::new();
+
+    for item in impl_block.items.iter_mut() {
+        if let ImplItem::Method(method) = item {
+            if let Some(scheduled_method) = get_scheduled_method(method)? {
+                scheduled_methods.push(scheduled_method);
+            }
+        }
+    }
+
+    Ok(scheduled_methods)
+}
+
 fn detect_factory_method(impl_block: &mut ItemImpl) -> Result<ItemFn, Error> {
     let factory_fn = impl_block.items.iter_mut().find_map(|item| {
         if let ImplItem::Method(method) = item {
@@ -231,6 +334,15 @@ fn detect_factory_method(impl_block: &mut ItemImpl) -> Result<ItemFn, Error> {
 
                 let factory_fn = syn::parse::<ItemFn>(fn_tokens.into()).unwrap();
 
+                // the #[inquirer] markers are kept in the parsed copy only
+                for arg in method.sig.inputs.iter_mut() {
+                    if let FnArg::Typed(typed) = arg {
+                        typed
+                            .attrs
+                            .retain(|attr| !attr.path.is_ident(INQUIRER_IDENT));
+                    }
+                }
+
                 return Some(factory_fn);
             } else {
                 return None;
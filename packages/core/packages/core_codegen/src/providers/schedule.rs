@@ -0,0 +1,230 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{
+    parse::Result, spanned::Spanned, Attribute, Error, FnArg, Ident, ImplItemMethod, Lit, LitInt,
+    LitStr, Meta, NestedMeta, Path,
+};
+
+const INTERVAL_IDENT: &str = "interval";
+const TIMEOUT_IDENT: &str = "timeout";
+const CRON_IDENT: &str = "cron";
+
+const MS_IDENT: &str = "ms";
+const NAME_IDENT: &str = "name";
+
+#[derive(Debug)]
+pub enum JobTrigger {
+    Interval(LitInt),
+    Timeout(LitInt),
+    Cron(LitStr),
+}
+
+#[derive(Debug)]
+pub struct ScheduledJob {
+    pub trigger: JobTrigger,
+    pub name: Option<LitStr>,
+}
+
+#[derive(Debug)]
+pub struct ScheduledMethod {
+    pub ident: Ident,
+    pub jobs: Vec<ScheduledJob>,
+    pub is_async: bool,
+}
+
+/// Collecting the `#[interval(ms = 100)]`, `#[timeout(ms = 100)]` and `#[cron("* * * * * *")]`
+/// attributes of the method. Every attribute accepts the optional `name = "job"`.
+///
+/// Supported signature: `fn(&self)`, sync or async, returning either `()` or `Result<(), E>`
+pub(crate) fn get_scheduled_method(method: &mut ImplItemMethod) -> Result<Option<ScheduledMethod>> {
+    let jobs = get_scheduled_jobs(&mut method.attrs)?;
+
+    if jobs.is_empty() {
+        return Ok(None);
+    }
+
+    let mut inputs = method.sig.inputs.iter();
+
+    let has_ref_receiver = matches!(
+        inputs.next(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some()
+    );
+
+    if !has_ref_receiver || inputs.next().is_some() {
+        return Err(Error::new(
+            method.sig.span(),
+            "Scheduled method must take only &self",
+        ));
+    }
+
+    Ok(Some(ScheduledMethod {
+        ident: method.sig.ident.clone(),
+        jobs,
+        is_async: method.sig.asyncness.is_some(),
+    }))
+}
+
+fn get_scheduled_jobs(attrs: &mut Vec<Attribute>) -> Result<Vec<ScheduledJob>> {
+    let mut jobs: Vec<ScheduledJob> = Vec::new();
+    let mut error: Option<Error> = None;
+
+    attrs.retain(|attr| {
+        let is_job_attr = [INTERVAL_IDENT, TIMEOUT_IDENT, CRON_IDENT]
+            .iter()
+            .any(|ident| attr.path.is_ident(ident));
+
+        if !is_job_attr {
+            return true;
+        }
+
+        match parse_job_attr(attr) {
+            Ok(job) => jobs.push(job),
+            Err(err) => error = Some(err),
+        }
+
+        false
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(jobs),
+    }
+}
+
+fn parse_job_attr(attr: &Attribute) -> Result<ScheduledJob> {
+    let meta_list = match attr.parse_meta()? {
+        Meta::List(meta_list) => meta_list,
+        meta => return Err(Error::new(meta.span(), "Expected job arguments")),
+    };
+
+    let mut ms: Option<LitInt> = None;
+    let mut expression: Option<LitStr> = None;
+    let mut name: Option<LitStr> = None;
+
+    for nested in meta_list.nested.iter() {
+        match nested {
+            NestedMeta::Lit(Lit::Str(value)) => expression = Some(value.clone()),
+            NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                match (&name_value.lit, name_value.path.get_ident()) {
+                    (Lit::Int(value), Some(ident)) if ident == MS_IDENT => ms = Some(value.clone()),
+                    (Lit::Str(value), Some(ident)) if ident == NAME_IDENT => {
+                        name = Some(value.clone())
+                    }
+                    _ => return Err(Error::new(name_value.span(), "Unknown job argument")),
+                }
+            }
+            nested => return Err(Error::new(nested.span(), "Unknown job argument")),
+        }
+    }
+
+    let trigger = if attr.path.is_ident(CRON_IDENT) {
+        let expression = expression
+            .ok_or_else(|| Error::new(attr.span(), "Expected #[cron(\"expression\")]"))?;
+
+        validate_cron_expression(&expression)?;
+
+        JobTrigger::Cron(expression)
+    } else {
+        let ms = ms.ok_or_else(|| Error::new(attr.span(), "Expected `ms = milliseconds`"))?;
+
+        if attr.path.is_ident(INTERVAL_IDENT) {
+            JobTrigger::Interval(ms)
+        } else {
+            JobTrigger::Timeout(ms)
+        }
+    };
+
+    Ok(ScheduledJob { trigger, name })
+}
+
+#[cfg(feature = "schedule")]
+fn validate_cron_expression(expression: &LitStr) -> Result<()> {
+    use std::str::FromStr;
+
+    cron::Schedule::from_str(&expression.value())
+        .map(|_schedule| ())
+        .map_err(|err| {
+            Error::new(
+                expression.span(),
+                format!("Invalid cron expression: {}", err),
+            )
+        })
+}
+
+// the scheduled jobs can't be compiled without the `schedule` feature anyway
+#[cfg(not(feature = "schedule"))]
+fn validate_cron_expression(_expression: &LitStr) -> Result<()> {
+    Ok(())
+}
+
+pub(crate) fn gen_scheduled_jobs_code(
+    self_ty: &TokenStream2,
+    interface: &Option<Path>,
+    scheduled_methods: &[ScheduledMethod],
+    path_to_lib: &TokenStream2,
+) -> TokenStream2 {
+    let schedule_path = &quote::quote! { #path_to_lib::modules::schedule };
+
+    let method_prefix = &match interface {
+        Some(interface) => quote::quote! { <#self_ty as #interface> },
+        None => quote::quote! { <#self_ty> },
+    };
+
+    let records = scheduled_methods.iter().flat_map(|scheduled_method| {
+        let ident = &scheduled_method.ident;
+        let method = ident.to_string();
+
+        let call = if scheduled_method.is_async {
+            quote::quote! { #method_prefix::#ident(&provider).await }
+        } else {
+            quote::quote! { #method_prefix::#ident(&provider) }
+        };
+
+        scheduled_method.jobs.iter().map(move |job| {
+            let trigger = match &job.trigger {
+                JobTrigger::Interval(ms) => {
+                    quote::quote! { #schedule_path::JobTrigger::Interval(#ms) }
+                }
+                JobTrigger::Timeout(ms) => {
+                    quote::quote! { #schedule_path::JobTrigger::Timeout(#ms) }
+                }
+                JobTrigger::Cron(expression) => {
+                    quote::quote! { #schedule_path::JobTrigger::Cron(String::from(#expression)) }
+                }
+            };
+
+            let name = match &job.name {
+                Some(name) => quote::quote! { Some(#name) },
+                None => quote::quote! { None },
+            };
+
+            quote::quote! {
+                const _: () = {
+                    #[#path_to_lib::blackbox_cast::linkme::distributed_slice(#schedule_path::SCHEDULED_JOBS)]
+                    #[linkme(crate = #path_to_lib::blackbox_cast::linkme)]
+                    fn __blackbox_scheduled_job() -> #schedule_path::ScheduledJobRecord {
+                        #schedule_path::ScheduledJobRecord {
+                            provider_id: ::std::any::TypeId::of::<#self_ty>(),
+                            method: #method,
+                            name: #name,
+                            trigger: #trigger,
+                            handler: |instance| {
+                                Box::pin(async move {
+                                    let provider = instance
+                                        .cast::<#self_ty>()
+                                        .map_err(|err| err.to_string())?
+                                        .as_ref();
+
+                                    #schedule_path::IntoJobResult::into_job_result(#call)
+                                })
+                            },
+                        }
+                    }
+                };
+            }
+        })
+    });
+
+    quote::quote! {
+        #(#records)*
+    }
+}
@@ -7,13 +7,20 @@ use crate::{
     cell::RefMut,
     compiler::ModuleCompiler,
     container::Container,
-    events::{OnModuleDestroy, OnModuleInit},
-    instance_wrapper::{InstanceToken, InstanceWrapper},
-    module::{Module, ModuleId},
-    modules::CoreModule,
+    dynamic_module::DynamicModule,
+    events::{OnApplicationBootstrap, OnModuleDestroy, OnModuleInit},
+    injectable::IInjectable,
+    instance_wrapper::{Instance, InstanceToken, InstanceWrapper, Scope},
+    interceptor::bind_container_interceptors,
+    metadata::Metadata,
+    module::{Module, ModuleId, ModuleToken},
+    module_context::ModuleContext,
+    modules::{event_emitter::subscribe_event_handlers, CoreModule},
     reference::Ref,
+    resolver::{resolve_instance, Resolver},
+    testing::CapturingLogger,
     tokens::get_token,
-    ILogger, Logger,
+    ConsoleLogger, ILogger, LogFormat, LogSink, Logger, LoggerConfig, DEFAULT_APP_NAME,
 };
 
 #[derive(Clone)]
@@ -34,6 +41,7 @@ impl InstanceLink {
 pub struct InstanceLinksHost {
     container: RefMut<Container>,
     instance_links: HashMap<InstanceToken, Vec<InstanceLink>>,
+    strict_root_module: Option<ModuleToken>,
 }
 
 impl InstanceLinksHost {
@@ -41,9 +49,15 @@ impl InstanceLinksHost {
         InstanceLinksHost {
             container,
             instance_links: HashMap::new(),
+            strict_root_module: None,
         }
     }
 
+    /// Hides the non-exported providers of the modules except the root module
+    pub fn set_strict(&mut self, root_module: ModuleToken) {
+        self.strict_root_module = Some(root_module);
+    }
+
     pub fn init(&mut self) {
         let modules = self.container.as_ref().get_modules();
 
@@ -70,16 +84,40 @@ impl InstanceLinksHost {
 
     pub fn get_by_token(&self, token: &InstanceToken) -> Option<Vec<InstanceLink>> {
         if let Some(instance_links) = self.instance_links.get(token) {
-            Some(instance_links.clone())
+            let instance_links = instance_links
+                .iter()
+                .filter(|instance_link| self.is_visible(instance_link))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if instance_links.is_empty() {
+                None
+            } else {
+                Some(instance_links)
+            }
         } else {
             None
         }
     }
+
+    fn is_visible(&self, instance_link: &InstanceLink) -> bool {
+        let root_module = match &self.strict_root_module {
+            Some(root_module) => root_module,
+            None => return true,
+        };
+
+        let token = instance_link.wrapper_ref.as_ref().get_token();
+        let host = instance_link.wrapper_ref.as_ref().get_host();
+        let host = host.as_ref();
+
+        &host.get_token() == root_module || host.get_exported_provider(&token).is_some()
+    }
 }
 
 pub struct BlackBoxApp {
     container: RefMut<Container>,
     instance_links_host: RefMut<InstanceLinksHost>,
+    closed: RefMut<bool>,
 }
 
 impl BlackBoxApp {
@@ -91,7 +129,27 @@ impl BlackBoxApp {
         BlackBoxApp {
             container,
             instance_links_host,
+            closed: RefMut::new(false),
+        }
+    }
+
+    /// Calls `on_module_destroy` of the providers and shuts down the log sinks. The hooks are called only once.
+    pub async fn close(&self) {
+        if std::mem::replace(&mut *self.closed.as_mut(), true) {
+            return;
         }
+
+        call_destroy_hook(self.container.clone()).await;
+
+        let logger = self.get_default_logger();
+
+        // use_logger was never called
+        if logger.is_buffer_attached() {
+            logger.flush();
+        }
+
+        // the sinks can block while writing the pending records
+        let _ = tokio::task::spawn_blocking(move || logger.shutdown_sinks()).await;
     }
 
     pub fn get_container(&self) -> RefMut<Container> {
@@ -104,82 +162,372 @@ impl BlackBoxApp {
     }
 
     /// Retrieves an instance of either injectable by token, otherwise, throws error.
+    ///
+    /// Transient providers have no shared instance, use `resolve` for them.
     pub fn get_by_token<TInjectable: ?Sized + CastFrom>(
         &self,
         token: &InstanceToken,
     ) -> Result<Ref<TInjectable>, String> {
+        let wrapper = self.get_wrapper_by_token(token)?;
+
+        if wrapper.as_ref().get_scope() == Scope::Transient {
+            return Err(format!(
+                "InstanceLinksHost: {} provider is transient, use resolve() to create its instance.",
+                token
+            ));
+        }
+
+        let instance = wrapper.as_ref().get_instance().ok_or_else(|| {
+            format!(
+                "InstanceLinksHost: {} static instance was not found.",
+                token
+            )
+        })?;
+
+        instance
+            .cast::<TInjectable>()
+            .map_err(|err| err.to_string())
+    }
+
+    /// Retrieves metadata specified with `#[metadata(...)]` on the injectable.
+    pub fn get_metadata<TInjectable: CastFrom>(&self) -> Result<Metadata, String> {
+        self.get_metadata_by_token(&get_token::<TInjectable>())
+    }
+
+    /// Retrieves metadata specified with `#[metadata(...)]` on the injectable by token.
+    pub fn get_metadata_by_token(&self, token: &InstanceToken) -> Result<Metadata, String> {
+        Ok(self.get_wrapper_by_token(token)?.as_ref().get_metadata())
+    }
+
+    /// Creates a new instance of either transient injectable, otherwise, returns the shared instance.
+    pub async fn resolve<TInjectable: CastFrom>(&self) -> Result<Ref<TInjectable>, String> {
+        self.resolve_by_token(&get_token::<TInjectable>()).await
+    }
+
+    /// Creates a new instance of either transient injectable by token, otherwise, returns the shared instance.
+    ///
+    /// Transient dependencies of the instance are created as well and receive the `on_module_init` event.
+    pub async fn resolve_by_token<TInjectable: ?Sized + CastFrom>(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<Ref<TInjectable>, String> {
+        let wrapper = self.get_wrapper_by_token(token)?;
+
+        let instance = resolve_instance(self.container.clone(), wrapper).await?;
+
+        instance
+            .cast::<TInjectable>()
+            .map_err(|err| err.to_string())
+    }
+
+    /// Returns the lookup bound to the module.
+    ///
+    /// Use it when the provider is registered in several modules.
+    pub fn select<TModule: ModuleCompiler>(&self) -> Result<ModuleContext, String> {
+        self.select_by_token(&get_token::<TModule>())
+    }
+
+    /// Returns the lookup bound to the module by token.
+    pub fn select_by_token(&self, token: &ModuleToken) -> Result<ModuleContext, String> {
+        let module = self
+            .container
+            .as_ref()
+            .get_module(token)
+            .ok_or_else(|| format!("InstanceLinksHost: {} module was not found.", token))?;
+
+        Ok(ModuleContext::new(self.container.clone(), module))
+    }
+
+    fn get_wrapper_by_token(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<RefMut<InstanceWrapper>, String> {
         let instance_links = self
             .instance_links_host
             .as_ref()
             .get_by_token(token)
-            .expect(format!("InstanceLinksHost: {} provider was not found.", token).as_str());
-
-        for instance_link in instance_links {
-            let wrapper = instance_link.wrapper_ref.clone();
-
-            return Ok(wrapper
-                .as_ref()
-                .get_instance()
-                .unwrap()
-                .cast::<TInjectable>()
-                .unwrap());
+            .ok_or_else(|| format!("InstanceLinksHost: {} provider was not found.", token))?;
+
+        if instance_links.len() > 1 {
+            let mut module_tokens = instance_links
+                .iter()
+                .map(|instance_link| {
+                    instance_link
+                        .wrapper_ref
+                        .as_ref()
+                        .get_host()
+                        .as_ref()
+                        .get_token()
+                })
+                .collect::<Vec<_>>();
+
+            module_tokens.sort();
+
+            return Err(format!(
+                "InstanceLinksHost: {} provider is ambiguous, it's registered in the {} modules. Use select() to specify the module.",
+                token,
+                module_tokens.join(", ")
+            ));
         }
 
-        return Err(format!(
-            "InstanceLinksHost: {} provider was not found.",
-            token
-        ));
+        Ok(instance_links[0].wrapper_ref.clone())
     }
 
+    /// Replaces the primary log sink, the console logger by default, and flushes the buffered logs.
+    ///
+    /// The sinks added by `log_sink` and `add_log_sink` are kept.
     pub fn use_logger(&self, logger: Ref<dyn ILogger>) {
-        let default_logger = self.get::<Logger>().unwrap();
+        let default_logger = self.get_default_logger();
 
         default_logger.as_ref().register_logger(logger);
         default_logger.as_ref().flush();
     }
+
+    /// Adds the log sink next to the registered ones
+    pub fn add_log_sink(&self, sink: LogSink) {
+        self.get_default_logger().as_ref().add_sink(sink);
+    }
+
+    fn get_logger_provider(&self, token: &InstanceToken) -> Result<Ref<dyn ILogger>, String> {
+        let wrapper = self.get_wrapper_by_token(token)?;
+
+        Resolver::new(self.container.clone())
+            .resolve(wrapper)?
+            .cast::<dyn ILogger>()
+            .map_err(|_| format!("The {} provider doesn't implement ILogger", token))
+    }
+
+    fn get_default_logger(&self) -> Ref<Logger> {
+        let wrapper = self.get_wrapper_by_token(&get_token::<Logger>()).unwrap();
+
+        Resolver::new(self.container.clone())
+            .resolve(wrapper)
+            .unwrap()
+            .cast::<Logger>()
+            .unwrap()
+    }
 }
 
 pub struct BuildParams {
     buffer_logs: bool,
+    defer_logger: bool,
+    log_buffer_capacity: Option<usize>,
+    strict: bool,
+    modules: Vec<DynamicModule>,
+    log_format: Option<LogFormat>,
+    log_sinks: Vec<LogSink>,
+    logger: Option<Ref<dyn ILogger>>,
+    logger_provider: Option<InstanceToken>,
+    silent: bool,
+    app_name: Option<String>,
+    overrides: Vec<(InstanceToken, Ref<dyn IInjectable>)>,
 }
 
 impl BuildParams {
     pub fn default() -> BuildParams {
-        BuildParams { buffer_logs: false }
+        BuildParams {
+            buffer_logs: false,
+            defer_logger: false,
+            log_buffer_capacity: None,
+            strict: false,
+            modules: Vec::new(),
+            log_format: None,
+            log_sinks: Vec::new(),
+            logger: None,
+            logger_provider: None,
+            silent: false,
+            app_name: None,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Imports the dynamic module, e.g. `ConfigModule::for_root(options)`
+    pub fn import(mut self, module: DynamicModule) -> Self {
+        self.modules.push(module);
+
+        return self;
     }
 
+    /// Buffers the bootstrap logs, they are flushed to the registered sinks at the end of the build.
+    ///
+    /// Use `defer_logger` to keep them until `use_logger` is called.
     pub fn buffer_logs(mut self) -> Self {
         self.buffer_logs = true;
 
         return self;
     }
+
+    /// Buffers the logs until `use_logger` is called, e.g. with a logger provider of the app
+    pub fn defer_logger(mut self) -> Self {
+        self.buffer_logs = true;
+        self.defer_logger = true;
+
+        return self;
+    }
+
+    /// Sets the max number of the buffered records, 1024 by default
+    pub fn log_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.log_buffer_capacity = Some(capacity);
+
+        return self;
+    }
+
+    /// Sets the format of the console logger, overrides the `BLACKBOX_LOG_FORMAT` env variable
+    pub fn log_format(mut self, format: LogFormat) -> Self {
+        self.log_format = Some(format);
+
+        return self;
+    }
+
+    /// Adds the log sink next to the console logger, e.g. `FileLogger`
+    pub fn log_sink(mut self, sink: LogSink) -> Self {
+        self.log_sinks.push(sink);
+
+        return self;
+    }
+
+    /// Replaces the console logger by the logger, the framework logs go to it from the start
+    pub fn logger(mut self, logger: Ref<dyn ILogger>) -> Self {
+        self.logger = Some(logger);
+        self.logger_provider = None;
+
+        return self;
+    }
+
+    /// Replaces the console logger by the provider implementing `ILogger`, it's resolved first during build
+    pub fn logger_provider<T: CastFrom>(mut self) -> Self {
+        self.logger_provider = Some(get_token::<T>());
+        self.logger = None;
+
+        return self;
+    }
+
+    /// Replaces the console logger by the capturing logger, the bootstrap logs are captured too
+    pub fn capture_logs(self, logger: &Ref<CapturingLogger>) -> Self {
+        self.logger(logger.clone().cast::<dyn ILogger>().unwrap())
+    }
+
+    /// Removes the console logger, only the sinks added by `log_sink` get the logs
+    pub fn silent(mut self) -> Self {
+        self.silent = true;
+
+        return self;
+    }
+
+    /// Sets the app name shown by the console logger, "App" by default
+    pub fn app_name(mut self, app_name: &str) -> Self {
+        self.app_name = Some(app_name.to_string());
+
+        return self;
+    }
+
+    /// Replaces the instance of the provider by the value in all modules, e.g. by the mock.
+    ///
+    /// The provider becomes a singleton and its dependencies are not created.
+    pub fn override_provider<T, V: CastFrom>(mut self, value: Ref<V>) -> Self {
+        let instance = value
+            .cast::<dyn IInjectable>()
+            .expect("The override value doesn't implement IInjectable");
+
+        self.overrides.push((get_token::<T>(), instance));
+
+        return self;
+    }
+
+    /// The app retrieves only providers of the root module and exported providers of other modules
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+
+        return self;
+    }
 }
 
-pub async fn build<TModule: ModuleCompiler>(params: BuildParams) -> Ref<BlackBoxApp> {
+pub async fn build<TModule: ModuleCompiler>(mut params: BuildParams) -> Ref<BlackBoxApp> {
     let builder = RefMut::new(Builder::new());
 
     let core_module_builder = builder.as_mut().register_module::<CoreModule>();
     CoreModule::__blackbox_build(core_module_builder);
 
+    for module in params.modules.drain(..) {
+        let token = module.get_token();
+
+        if let Err(err) = module.build(&builder.as_ref()) {
+            panic!("The {} module failed to build: {}", token, err);
+        }
+    }
+
     let root_module_builder = builder.as_mut().register_module::<TModule>();
     TModule::__blackbox_build(root_module_builder);
 
+    for (token, instance) in params.overrides.drain(..) {
+        if !override_provider(builder.clone(), &token, instance) {
+            panic!("The {} provider to override was not found", token);
+        }
+    }
+
     init(builder.clone());
     link(builder.clone());
 
     let app = builder.as_ref().build();
 
+    if params.strict {
+        app.instance_links_host
+            .as_mut()
+            .set_strict(get_token::<TModule>());
+    }
+
     let container = app.as_ref().get_container();
+
+    bind_container_interceptors(container.clone());
+
+    subscribe_event_handlers(&container);
+
     let mut modules = container.as_ref().get_modules_sorted_by_distance();
 
     modules.reverse();
 
-    let logger = app.get::<Logger>().unwrap();
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    if params.silent {
+        logger.clear_sinks();
+    } else if let Some(custom_logger) = params.logger.take() {
+        logger.register_logger(custom_logger);
+    } else if let Some(token) = &params.logger_provider {
+        match app.get_logger_provider(token) {
+            Ok(custom_logger) => logger.register_logger(custom_logger),
+            Err(err) => panic!("The logger provider failed to resolve: {}", err),
+        }
+    } else if params.log_format.is_some() || params.app_name.is_some() {
+        let app_name = params
+            .app_name
+            .clone()
+            .unwrap_or_else(|| DEFAULT_APP_NAME.to_string());
+        let mut console_logger = ConsoleLogger::new(app_name);
+
+        if let Some(format) = params.log_format {
+            console_logger = console_logger.with_format(format);
+        }
+
+        logger.register_logger(Ref::new(console_logger).cast::<dyn ILogger>().unwrap());
+    }
+
+    for sink in params.log_sinks.drain(..) {
+        logger.add_sink(sink);
+    }
+
+    if let Some(capacity) = params.log_buffer_capacity {
+        logger.set_buffer_capacity(capacity);
+    }
 
     if params.buffer_logs {
         logger.attach_buffer();
     }
 
+    // the env filter is parsed before the configured logger is installed
+    if let Some(err) = app.resolve::<LoggerConfig>().await.unwrap().get_env_error() {
+        logger.warn_with_ctx(err.as_str(), "LoggerConfig");
+    }
+
     for module in modules {
         let token = module.as_ref().get_token();
 
@@ -198,9 +546,34 @@ pub async fn build<TModule: ModuleCompiler>(params: BuildParams) -> Ref<BlackBox
 
     call_init_hook(app.clone()).await;
 
+    call_bootstrap_hook(app.clone()).await;
+
+    // the buffered logs wait for use_logger only if the logger is deferred
+    if logger.is_buffer_attached() && !params.defer_logger {
+        logger.flush();
+    }
+
     return app;
 }
 
+fn override_provider(
+    builder: RefMut<Builder>,
+    token: &InstanceToken,
+    instance: Ref<dyn IInjectable>,
+) -> bool {
+    let mut is_overridden = false;
+
+    for (_token, module_builder) in builder.as_ref().get_modules() {
+        if let Some(provider_builder) = module_builder.providers.as_ref().get(token) {
+            provider_builder.override_value(instance.clone());
+
+            is_overridden = true;
+        }
+    }
+
+    is_overridden
+}
+
 fn init(builder: RefMut<Builder>) {
     let module_builders = builder.as_ref().get_modules();
 
@@ -252,21 +625,43 @@ async fn call_init_hook(app: Ref<BlackBoxApp>) {
         for (_token, provider) in providers {
             let instances = provider.as_ref().get_instances();
 
-            for instance in instances {
-                if let Ok(provider) = instance.cast::<dyn OnModuleInit>() {
-                    provider.as_ref().on_module_init().await;
-                }
-            }
+            call_init_hook_on_instances(instances).await;
+        }
+    }
+}
+
+pub(crate) async fn call_init_hook_on_instances(instances: Vec<Instance>) {
+    for instance in instances {
+        if let Ok(provider) = instance.cast::<dyn OnModuleInit>() {
+            provider.as_ref().on_module_init().await;
         }
     }
 }
 
-async fn call_destroy_hook(app: Ref<BlackBoxApp>) {
+async fn call_bootstrap_hook(app: Ref<BlackBoxApp>) {
     let modules = app
         .get_container()
         .as_ref()
         .get_modules_sorted_by_distance();
 
+    for module in modules {
+        let providers = module.as_ref().get_providers();
+
+        for (_token, provider) in providers {
+            let instances = provider.as_ref().get_instances();
+
+            for instance in instances {
+                if let Ok(provider) = instance.cast::<dyn OnApplicationBootstrap>() {
+                    provider.as_ref().on_application_bootstrap().await;
+                }
+            }
+        }
+    }
+}
+
+async fn call_destroy_hook(container: RefMut<Container>) {
+    let modules = container.as_ref().get_modules_sorted_by_distance();
+
     for module in modules {
         let providers = module.as_ref().get_providers();
 
@@ -286,6 +681,6 @@ fn listen_exit_signal(app: Ref<BlackBoxApp>) {
     tokio::spawn(async move {
         tokio::signal::ctrl_c().await.unwrap();
 
-        call_destroy_hook(app).await;
+        app.close().await;
     });
 }
@@ -8,12 +8,14 @@ use crate::{
     container::Container,
     factory::Factory,
     injectable::IInjectable,
+    inquirer::Inquirer,
     instance_wrapper::{InstanceToken, InstanceWrapper, Scope, STATIC_CONTEXT},
+    metadata::{get_methods_metadata, MetadataValue},
     module::{Module, ModuleDistance, ModuleToken},
     tokens::get_token,
 };
 
-pub type FactoryFn = Box<fn() -> Ref<dyn IInjectable>>;
+pub type FactoryFn = Box<fn(&Inquirer) -> Ref<dyn IInjectable>>;
 pub type DepInitFn = Box<dyn Fn(Ref<dyn IInjectable>, Ref<dyn IInjectable>) -> ()>; // (self_, dep) -> ()
 
 pub struct ProviderBuilder {
@@ -24,7 +26,11 @@ pub struct ProviderBuilder {
 }
 
 fn make_factory_fn<T: Factory + CastFrom>() -> FactoryFn {
-    Box::new(|| T::__blackbox_create().cast::<dyn IInjectable>().unwrap())
+    Box::new(|inquirer| {
+        T::__blackbox_create(inquirer)
+            .cast::<dyn IInjectable>()
+            .unwrap()
+    })
 }
 
 impl ProviderBuilder {
@@ -34,8 +40,45 @@ impl ProviderBuilder {
         context: RefMut<BuilderContext>,
     ) -> ProviderBuilder {
         let factory_fn = make_factory_fn::<T>();
+        let dep_init_fns = RefMut::new(HashMap::new());
         let instance_wrapper = RefMut::new(InstanceWrapper::new(token, host.clone()));
 
+        instance_wrapper
+            .as_mut()
+            .set_type_name(std::any::type_name::<T>().to_string());
+        instance_wrapper
+            .as_mut()
+            .set_type_id(std::any::TypeId::of::<T>());
+        instance_wrapper.as_mut().set_factory_fn(factory_fn.clone());
+        instance_wrapper
+            .as_mut()
+            .set_dep_init_fns(dep_init_fns.clone());
+        instance_wrapper
+            .as_mut()
+            .set_methods_metadata(get_methods_metadata::<T>());
+
+        host.as_mut().register_provider(instance_wrapper.clone());
+
+        ProviderBuilder {
+            context,
+            factory_fn,
+            dep_init_fns,
+            instance_wrapper,
+        }
+    }
+
+    /// Provider of the already created instance
+    pub fn new_value(
+        token: InstanceToken,
+        host: RefMut<Module>,
+        context: RefMut<BuilderContext>,
+        instance: Ref<dyn IInjectable>,
+    ) -> ProviderBuilder {
+        let factory_fn: FactoryFn = Box::new(|_| panic!("Value providers have no factory"));
+        let instance_wrapper = RefMut::new(InstanceWrapper::new(token, host.clone()));
+
+        instance_wrapper.as_mut().set_instance(instance);
+
         host.as_mut().register_provider(instance_wrapper.clone());
 
         ProviderBuilder {
@@ -46,11 +89,33 @@ impl ProviderBuilder {
         }
     }
 
+    /// Replaces the instance of the provider by the value, the provider becomes a singleton without dependencies
+    pub fn override_value(&self, instance: Ref<dyn IInjectable>) -> &Self {
+        self.dep_init_fns.as_mut().clear();
+
+        let mut instance_wrapper = self.instance_wrapper.as_mut();
+
+        instance_wrapper.set_scope(Scope::Singleton);
+        instance_wrapper.set_instance(instance);
+
+        return self;
+    }
+
     pub fn set_scope(&self, scope: Scope) -> &Self {
         self.instance_wrapper.as_mut().set_scope(scope);
         return self;
     }
 
+    pub fn set_metadata<V: Into<MetadataValue>>(&self, key: &str, value: V) -> &Self {
+        let mut metadata = self.instance_wrapper.as_ref().get_metadata();
+
+        metadata.set(key, value);
+
+        self.instance_wrapper.as_mut().set_metadata(metadata);
+
+        self
+    }
+
     pub fn should_be_exported(&self) -> &Self {
         let token = self.instance_wrapper.as_ref().get_token();
         let host = self.instance_wrapper.as_ref().get_host();
@@ -171,7 +236,11 @@ impl ProviderBuilder {
                     return self;
                 }
 
-                let instance = (provider_builder.factory_fn)();
+                let inquirer = Inquirer::from_wrapper(
+                    &self.instance_wrapper,
+                    &self.context.as_ref().container,
+                );
+                let instance = (provider_builder.factory_fn)(&inquirer);
 
                 instance_wrapper.as_mut().set_instance_by_inquirer_id(
                     inquirer_id,
@@ -186,7 +255,9 @@ impl ProviderBuilder {
                     return self;
                 }
 
-                let instance = (provider_builder.factory_fn)();
+                let inquirer =
+                    Inquirer::from_wrapper(&instance_wrapper, &self.context.as_ref().container);
+                let instance = (provider_builder.factory_fn)(&inquirer);
 
                 instance_wrapper.as_mut().set_instance(instance.clone());
 
@@ -302,6 +373,25 @@ impl ModuleBuilder {
         return provider_builder;
     }
 
+    pub fn register_value(
+        &self,
+        token: InstanceToken,
+        instance: Ref<dyn IInjectable>,
+    ) -> Ref<ProviderBuilder> {
+        let provider_builder = Ref::new(ProviderBuilder::new_value(
+            token.clone(),
+            self.module.clone(),
+            self.context.clone(),
+            instance,
+        ));
+
+        self.providers
+            .as_mut()
+            .insert(token, provider_builder.clone());
+
+        provider_builder
+    }
+
     pub fn get_context(&self) -> RefMut<BuilderContext> {
         self.context.clone()
     }
@@ -354,6 +444,11 @@ impl Builder {
     }
 
     pub fn build(&self) -> Ref<BlackBoxApp> {
-        Ref::new(BlackBoxApp::new(self.context.as_ref().container.clone()))
+        let mut context = self.context.as_mut();
+
+        // the module builders hold the context, drop them to not keep the container alive
+        context.modules.clear();
+
+        Ref::new(BlackBoxApp::new(context.container.clone()))
     }
 }
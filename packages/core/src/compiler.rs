@@ -7,6 +7,7 @@ use crate::{
     container::Container,
     factory::Factory,
     injectable::IInjectable,
+    inquirer::Inquirer,
     instance_wrapper::{ContextId, InquirerId, InstanceToken, Scope},
     module::{Module, ModuleToken},
     reference::Ref,
@@ -80,7 +81,10 @@ pub fn make_instance<T: Factory + CastFrom>(
                 return instance;
             }
 
-            let instance = T::__blackbox_create().cast::<dyn IInjectable>().unwrap();
+            let inquirer = Inquirer::from_wrapper(&instance_wrapper, &container);
+            let instance = T::__blackbox_create(&inquirer)
+                .cast::<dyn IInjectable>()
+                .unwrap();
 
             instance_wrapper.as_mut().set_instance_by_inquirer_id(
                 inquirer_id,
@@ -95,7 +99,10 @@ pub fn make_instance<T: Factory + CastFrom>(
                 return instance;
             }
 
-            let instance = T::__blackbox_create().cast::<dyn IInjectable>().unwrap();
+            let inquirer = Inquirer::from_wrapper(&instance_wrapper, &container);
+            let instance = T::__blackbox_create(&inquirer)
+                .cast::<dyn IInjectable>()
+                .unwrap();
 
             instance_wrapper.as_mut().set_instance(instance.clone());
 
@@ -0,0 +1,36 @@
+use crate::{
+    builder::{Builder, ModuleBuilder},
+    cell::Ref,
+    module::ModuleToken,
+    tokens::get_token,
+};
+
+pub type DynamicModuleBuildFn = Box<dyn FnOnce(&Builder) -> Result<(), String>>;
+
+/// Module configured at runtime, e.g. `ConfigModule::for_root(options)`.
+///
+/// Dynamic modules are imported by `BuildParams::import` and built before the root module.
+pub struct DynamicModule {
+    token: ModuleToken,
+    build_fn: DynamicModuleBuildFn,
+}
+
+impl DynamicModule {
+    pub fn new<T, F>(build_fn: F) -> DynamicModule
+    where
+        F: FnOnce(Ref<ModuleBuilder>) -> Result<(), String> + 'static,
+    {
+        DynamicModule {
+            token: get_token::<T>(),
+            build_fn: Box::new(move |builder: &Builder| build_fn(builder.register_module::<T>())),
+        }
+    }
+
+    pub fn get_token(&self) -> ModuleToken {
+        self.token.clone()
+    }
+
+    pub(crate) fn build(self, builder: &Builder) -> Result<(), String> {
+        (self.build_fn)(builder)
+    }
+}
@@ -6,6 +6,12 @@ pub trait OnModuleInit {
     async fn on_module_init(&self);
 }
 
+#[interface]
+#[blackbox_di(local)]
+pub trait OnApplicationBootstrap {
+    async fn on_application_bootstrap(&self);
+}
+
 #[interface]
 #[blackbox_di(local)]
 pub trait OnModuleDestroy {
@@ -1,9 +1,9 @@
 use blackbox_cast::CastFrom;
 
-use crate::reference::Ref;
+use crate::{inquirer::Inquirer, reference::Ref};
 
 pub trait Factory {
-    fn __blackbox_create() -> Ref<Self>
+    fn __blackbox_create(inquirer: &Inquirer) -> Ref<Self>
     where
         Self: CastFrom;
 }
@@ -0,0 +1,101 @@
+use crate::{
+    cell::{RefMut, WeakRefMut},
+    container::Container,
+    instance_wrapper::{InquirerId, InstanceToken, InstanceWrapper},
+    module::{Module, ModuleToken},
+    module_context::ModuleContext,
+};
+
+/// The provider requesting the instance, passed to the factories.
+///
+/// Transient providers get their inquirer, singleton providers and the instances
+/// resolved by the app directly inquire themselves.
+///
+/// The container and the module are held weakly, so the providers keeping the inquirer
+/// don't keep the container alive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inquirer {
+    id: InquirerId,
+    token: InstanceToken,
+    type_name: String,
+    module: ModuleToken,
+    host: WeakRefMut<Module>,
+    container: WeakRefMut<Container>,
+}
+
+impl Inquirer {
+    pub(crate) fn from_wrapper(
+        wrapper: &RefMut<InstanceWrapper>,
+        container: &RefMut<Container>,
+    ) -> Inquirer {
+        let wrapper = wrapper.as_ref();
+        let host = wrapper.get_host();
+        let module = host.as_ref().get_token();
+
+        Inquirer {
+            id: wrapper.get_id(),
+            token: wrapper.get_token(),
+            type_name: wrapper.get_type_name(),
+            module,
+            host: host.downgrade(),
+            container: container.downgrade(),
+        }
+    }
+
+    pub fn get_id(&self) -> InquirerId {
+        self.id.clone()
+    }
+
+    pub fn get_token(&self) -> InstanceToken {
+        self.token.clone()
+    }
+
+    /// Returns the full type name of the provider, it's the token for the value providers
+    pub fn get_type_name(&self) -> String {
+        self.type_name.clone()
+    }
+
+    /// Returns the type name without the module path, e.g. `UserService`
+    pub fn get_name(&self) -> String {
+        let type_name = self
+            .type_name
+            .split('<')
+            .next()
+            .unwrap_or(self.type_name.as_str());
+
+        type_name
+            .rsplit("::")
+            .next()
+            .unwrap_or(type_name)
+            .to_string()
+    }
+
+    pub fn get_module(&self) -> ModuleToken {
+        self.module.clone()
+    }
+
+    pub(crate) fn get_container(&self) -> Result<RefMut<Container>, String> {
+        self.container.upgrade().ok_or_else(|| {
+            format!(
+                "Inquirer: the container of the {} provider is dropped",
+                &self.token
+            )
+        })
+    }
+
+    /// Returns the lookup bound to the module of the provider, it fails after the app is dropped
+    pub fn get_module_context(&self) -> Result<ModuleContext, String> {
+        let container = self.get_container()?;
+        let host = self
+            .host
+            .upgrade()
+            .ok_or_else(|| format!("Inquirer: the {} module is dropped", &self.module))?;
+
+        Ok(ModuleContext::new(container, host))
+    }
+
+    /// Checks if the provider inquires itself
+    pub fn is_self(&self, token: &InstanceToken) -> bool {
+        &self.token == token
+    }
+}
@@ -1,7 +1,12 @@
-use std::collections::HashMap;
+use std::{any::TypeId, collections::HashMap, sync::Arc};
 
 use crate::{
-    container::Container, injectable::IInjectable, module::Module, reference::Ref,
+    builder::{DepInitFn, FactoryFn},
+    injectable::IInjectable,
+    interceptor::InterceptorBinding,
+    metadata::Metadata,
+    module::Module,
+    reference::Ref,
     reference_mut::RefMut,
 };
 
@@ -28,25 +33,37 @@ pub type InquirerId = InstanceWrapperId;
 pub type InstanceToken = String;
 pub type ContextId = String;
 pub type Instance = Ref<dyn IInjectable>;
-pub type FactoryFn =
-    fn(token: InstanceToken, module: RefMut<Module>, container: RefMut<Container>) -> Instance;
 
 pub struct InstanceWrapper {
     id: InstanceWrapperId,
     token: InstanceToken,
+    type_name: String,
+    type_id: Option<TypeId>,
     host: RefMut<Module>,
     scope: Scope,
+    factory_fn: Option<FactoryFn>,
+    dep_init_fns: RefMut<HashMap<InstanceToken, DepInitFn>>,
+    metadata: Metadata,
+    methods_metadata: HashMap<String, Metadata>,
     instance_collection: HashMap<InquirerId, HashMap<ContextId, Instance>>,
+    interceptor_bindings: HashMap<usize, Arc<InterceptorBinding>>,
 }
 
 impl InstanceWrapper {
     pub fn new(token: InstanceToken, host: RefMut<Module>) -> InstanceWrapper {
         InstanceWrapper {
             id: gen_instance_wrapper_id(),
+            type_name: token.clone(),
+            type_id: None,
             token,
             host,
             scope: Scope::default(),
+            factory_fn: None,
+            dep_init_fns: RefMut::new(HashMap::new()),
+            metadata: Metadata::new(),
+            methods_metadata: HashMap::new(),
             instance_collection: HashMap::new(),
+            interceptor_bindings: HashMap::new(),
         }
     }
 
@@ -58,6 +75,24 @@ impl InstanceWrapper {
         self.token.clone()
     }
 
+    /// Returns the type name of the provider, it's the token if the type is unknown
+    pub fn get_type_name(&self) -> String {
+        self.type_name.clone()
+    }
+
+    pub fn set_type_name(&mut self, type_name: String) {
+        self.type_name = type_name;
+    }
+
+    /// Returns the type id of the provider, value providers have no type id
+    pub fn get_type_id(&self) -> Option<TypeId> {
+        self.type_id
+    }
+
+    pub fn set_type_id(&mut self, type_id: TypeId) {
+        self.type_id = Some(type_id);
+    }
+
     pub fn get_host(&self) -> RefMut<Module> {
         self.host.clone()
     }
@@ -70,6 +105,75 @@ impl InstanceWrapper {
         self.scope = scope;
     }
 
+    pub fn get_metadata(&self) -> Metadata {
+        self.metadata.clone()
+    }
+
+    pub fn set_metadata(&mut self, metadata: Metadata) {
+        self.metadata = metadata;
+    }
+
+    pub fn get_method_metadata(&self, method: &str) -> Option<Metadata> {
+        self.methods_metadata.get(method).cloned()
+    }
+
+    pub fn get_methods_metadata(&self) -> HashMap<String, Metadata> {
+        self.methods_metadata.clone()
+    }
+
+    pub fn set_methods_metadata(&mut self, methods_metadata: HashMap<String, Metadata>) {
+        self.methods_metadata = methods_metadata;
+    }
+
+    pub fn set_factory_fn(&mut self, factory_fn: FactoryFn) {
+        self.factory_fn = Some(factory_fn);
+    }
+
+    pub fn get_factory_fn(&self) -> Option<FactoryFn> {
+        self.factory_fn.clone()
+    }
+
+    pub fn set_dep_init_fns(&mut self, dep_init_fns: RefMut<HashMap<InstanceToken, DepInitFn>>) {
+        self.dep_init_fns = dep_init_fns;
+    }
+
+    /// Returns tokens of the dependencies which should be linked to every instance
+    pub fn get_dep_tokens(&self) -> Vec<InstanceToken> {
+        self.dep_init_fns.as_ref().keys().cloned().collect()
+    }
+
+    /// Links the dependency instance to the instance of the provider
+    pub fn link_dependency(
+        &self,
+        token: &InstanceToken,
+        self_instance: Instance,
+        dep_instance: Instance,
+    ) -> Result<(), String> {
+        let dep_init_fns = self.dep_init_fns.as_ref();
+
+        match dep_init_fns.get(token) {
+            Some(dep_init_fn) => {
+                (dep_init_fn)(self_instance, dep_instance);
+                Ok(())
+            }
+            None => Err(format!(
+                "The {} dependency of the {} provider was not found",
+                token, &self.token
+            )),
+        }
+    }
+
+    /// Keeps the interceptors bound to the instance, the bindings of the dropped instances are removed
+    pub(crate) fn add_interceptor_binding(
+        &mut self,
+        address: usize,
+        binding: Arc<InterceptorBinding>,
+    ) {
+        self.interceptor_bindings
+            .retain(|_address, binding| binding.is_alive());
+        self.interceptor_bindings.insert(address, binding);
+    }
+
     pub fn has_instance(&self) -> bool {
         self.get_instance().is_some()
     }
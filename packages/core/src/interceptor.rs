@@ -0,0 +1,283 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock, Weak},
+};
+
+use blackbox_cast::linkme::distributed_slice;
+use once_cell::sync::Lazy;
+
+use crate::{
+    cell::{Ref, RefMut},
+    container::Container,
+    injectable::IInjectable,
+    instance_wrapper::{Instance, InstanceToken, InstanceWrapper},
+    interface,
+    resolver::Resolver,
+};
+
+pub type CallResult = Box<dyn Any + Send>;
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+pub type SyncNext<'a> = dyn Fn() -> CallResult + 'a;
+pub type AsyncNext<'a> = dyn Fn() -> BoxFuture<'a, CallResult> + Send + Sync + 'a;
+
+/// Metadata of the intercepted call
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallMetadata {
+    pub provider: InstanceToken,
+    pub interface: Option<&'static str>,
+    pub method: &'static str,
+}
+
+/// Interceptor of the provider methods marked with `#[intercept(Interceptor)]`.
+///
+/// `next` calls the next interceptor or the method itself, it can be called several times, e.g. for retries.
+/// The result is the boxed return value of the method.
+#[interface]
+#[blackbox_di(crate)]
+pub trait IInterceptor {
+    fn intercept(&self, _call: &CallMetadata, next: &SyncNext<'_>) -> CallResult {
+        next()
+    }
+
+    async fn intercept_async(&self, _call: &CallMetadata, next: &AsyncNext<'_>) -> CallResult {
+        next().await
+    }
+}
+
+/// The interceptor of the provider collected by `#[implements]`
+pub struct InterceptorRecord {
+    pub provider_id: TypeId,
+    pub interceptor_id: TypeId,
+    pub interceptor_token: InstanceToken,
+}
+
+#[distributed_slice]
+#[linkme(crate = blackbox_cast::linkme)]
+pub static INTERCEPTORS: [fn() -> InterceptorRecord] = [..];
+
+/// The call of the intercepted method generated by `#[implements]`
+pub struct InterceptedCall {
+    pub interface: Option<&'static str>,
+    pub method: &'static str,
+    pub interceptors: Vec<TypeId>,
+}
+
+/// The interceptors bound to the instance, owned by the wrapper of the provider
+pub(crate) struct InterceptorBinding {
+    instance: Weak<dyn IInjectable>,
+    provider: InstanceToken,
+    interceptors: HashMap<TypeId, Ref<dyn IInterceptor>>,
+}
+
+impl InterceptorBinding {
+    pub(crate) fn is_alive(&self) -> bool {
+        self.instance.strong_count() > 0
+    }
+}
+
+// the generated methods have only `&self`, so the bindings are found by the instance address.
+// The index holds weak refs, the bindings are dropped with their wrappers.
+static INTERCEPTOR_INDEX: Lazy<RwLock<HashMap<usize, Weak<InterceptorBinding>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn get_address<T: ?Sized>(instance: *const T) -> usize {
+    instance as *const () as usize
+}
+
+fn get_call_interceptors<T: ?Sized>(
+    instance: &T,
+    call: &InterceptedCall,
+) -> Option<(CallMetadata, Vec<Ref<dyn IInterceptor>>)> {
+    let binding = INTERCEPTOR_INDEX
+        .read()
+        .unwrap()
+        .get(&get_address(instance))?
+        .upgrade()?;
+
+    // the instance is dropped
+    binding.instance.upgrade()?;
+
+    let metadata = CallMetadata {
+        provider: binding.provider.clone(),
+        interface: call.interface,
+        method: call.method,
+    };
+
+    let interceptors = call
+        .interceptors
+        .iter()
+        .filter_map(|id| binding.interceptors.get(id).cloned())
+        .collect();
+
+    Some((metadata, interceptors))
+}
+
+/// Calls the sync method through its interceptors
+pub fn intercept_call<T: ?Sized>(
+    instance: &T,
+    call: &InterceptedCall,
+    next: &SyncNext<'_>,
+) -> CallResult {
+    match get_call_interceptors(instance, call) {
+        Some((metadata, interceptors)) => chain_call(&interceptors, &metadata, next),
+        None => next(),
+    }
+}
+
+fn chain_call(
+    interceptors: &[Ref<dyn IInterceptor>],
+    call: &CallMetadata,
+    next: &SyncNext<'_>,
+) -> CallResult {
+    match interceptors.split_first() {
+        Some((interceptor, rest)) => interceptor.intercept(call, &|| chain_call(rest, call, next)),
+        None => next(),
+    }
+}
+
+/// Calls the async method through its interceptors
+pub async fn intercept_async_call<T: ?Sized>(
+    instance: &T,
+    call: &InterceptedCall,
+    next: &AsyncNext<'_>,
+) -> CallResult {
+    match get_call_interceptors(instance, call) {
+        Some((metadata, interceptors)) => chain_async_call(&interceptors, &metadata, next).await,
+        None => next().await,
+    }
+}
+
+fn chain_async_call<'a>(
+    interceptors: &'a [Ref<dyn IInterceptor>],
+    call: &'a CallMetadata,
+    next: &'a AsyncNext<'_>,
+) -> BoxFuture<'a, CallResult> {
+    match interceptors.split_first() {
+        Some((interceptor, rest)) => Box::pin(async move {
+            interceptor
+                .intercept_async(call, &move || chain_async_call(rest, call, next))
+                .await
+        }),
+        None => next(),
+    }
+}
+
+/// Binds the interceptors of the provider to its instance
+pub(crate) fn bind_interceptors(
+    container: RefMut<Container>,
+    wrapper: &RefMut<InstanceWrapper>,
+    instance: &Instance,
+) {
+    let instance = instance.as_ref();
+    let provider_id = (*instance).type_id();
+
+    let records = INTERCEPTORS
+        .iter()
+        .map(|record_fn| (record_fn)())
+        .filter(|record| record.provider_id == provider_id)
+        .collect::<Vec<_>>();
+
+    if records.is_empty() {
+        return;
+    }
+
+    let (token, host) = {
+        let wrapper = wrapper.as_ref();
+
+        (wrapper.get_token(), wrapper.get_host())
+    };
+
+    let mut interceptors: HashMap<TypeId, Ref<dyn IInterceptor>> = HashMap::new();
+
+    for record in records {
+        let interceptor_wrapper = container
+            .as_ref()
+            .get_provider_in_module(&record.interceptor_token, host.clone())
+            .unwrap_or_else(|| {
+                panic!(
+                    "The {} interceptor of the {} provider in the {} module was not found",
+                    &record.interceptor_token,
+                    &token,
+                    &host.as_ref().get_token(),
+                )
+            });
+
+        let interceptor = Resolver::new(container.clone())
+            .resolve(interceptor_wrapper)
+            .and_then(|instance| {
+                instance
+                    .cast::<dyn IInterceptor>()
+                    .map_err(|err| err.to_string())
+            })
+            .unwrap_or_else(|err| {
+                panic!(
+                    "The {} interceptor of the {} provider is invalid: {}",
+                    &record.interceptor_token, &token, err
+                )
+            });
+
+        interceptors.insert(record.interceptor_id, interceptor);
+    }
+
+    let address = get_address(Arc::as_ptr(&instance));
+    let binding = Arc::new(InterceptorBinding {
+        instance: Arc::downgrade(&instance),
+        provider: token,
+        interceptors,
+    });
+
+    wrapper
+        .as_mut()
+        .add_interceptor_binding(address, binding.clone());
+
+    let mut index = INTERCEPTOR_INDEX.write().unwrap();
+
+    index.retain(|_address, binding| binding.strong_count() > 0);
+    index.insert(address, Arc::downgrade(&binding));
+}
+
+/// Binds the interceptors of all instances of the container
+pub(crate) fn bind_container_interceptors(container: RefMut<Container>) {
+    let modules = container.as_ref().get_modules_sorted_by_distance();
+
+    for module in modules {
+        for (_token, wrapper) in module.as_ref().get_providers() {
+            let instances = wrapper.as_ref().get_instances();
+
+            for instance in instances {
+                bind_interceptors(container.clone(), &wrapper, &instance);
+            }
+        }
+    }
+}
+
+/// `next` can be called several times, so the arguments of the intercepted methods are cloned for every call
+#[diagnostic::on_unimplemented(
+    message = "the arguments of the intercepted methods must implement Clone, `{Self}` doesn't",
+    note = "`next` of the interceptor can be called several times, so the arguments are cloned"
+)]
+pub trait InterceptedArg {
+    fn clone_arg(&self) -> Self;
+}
+
+impl<T: Clone> InterceptedArg for T {
+    fn clone_arg(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Clones the argument of the intercepted method in the generated code
+pub fn clone_arg<T: InterceptedArg>(arg: &T) -> T {
+    arg.clone_arg()
+}
+
+/// Infers the type of the async continuation in the generated code
+pub fn async_next<'a, F>(next: F) -> F
+where
+    F: Fn() -> BoxFuture<'a, CallResult> + Send + Sync + 'a,
+{
+    next
+}
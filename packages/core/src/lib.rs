@@ -2,23 +2,43 @@ pub mod app;
 pub mod builder;
 pub mod compiler;
 pub mod container;
+pub mod dynamic_module;
 pub mod events;
 pub mod factory;
 pub mod injectable;
+pub mod inquirer;
 pub mod instance_wrapper;
+pub mod interceptor;
 pub mod lazy;
+pub mod metadata;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod module;
+pub mod module_context;
 pub mod modules;
 mod reference;
 mod reference_mut;
+pub mod resolver;
+pub mod testing;
 pub mod tokens;
 
+#[cfg(test)]
+mod tests;
+
 pub mod cell {
     pub use crate::reference::*;
     pub use crate::reference_mut::*;
 }
 
+#[cfg(feature = "config")]
+pub use modules::config::*;
+pub use modules::discovery::*;
+pub use modules::event_emitter::*;
+pub use modules::health::*;
 pub use modules::logger::*;
+pub use modules::module_ref::*;
+#[cfg(feature = "schedule")]
+pub use modules::schedule::*;
 
 pub use blackbox_core_codegen::*;
 
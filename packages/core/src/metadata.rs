@@ -0,0 +1,192 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use blackbox_cast::linkme::distributed_slice;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    String(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Display for MetadataValue {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MetadataValue::String(value) => write!(f, "{}", value),
+            MetadataValue::Int(value) => write!(f, "{}", value),
+            MetadataValue::Float(value) => write!(f, "{}", value),
+            MetadataValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self {
+        MetadataValue::String(value.to_string())
+    }
+}
+
+impl From<String> for MetadataValue {
+    fn from(value: String) -> Self {
+        MetadataValue::String(value)
+    }
+}
+
+impl From<i64> for MetadataValue {
+    fn from(value: i64) -> Self {
+        MetadataValue::Int(value)
+    }
+}
+
+impl From<f64> for MetadataValue {
+    fn from(value: f64) -> Self {
+        MetadataValue::Float(value)
+    }
+}
+
+impl From<bool> for MetadataValue {
+    fn from(value: bool) -> Self {
+        MetadataValue::Bool(value)
+    }
+}
+
+/// Conversion of the metadata value to the typed value
+pub trait FromMetadataValue: Sized {
+    fn from_metadata_value(value: &MetadataValue) -> Option<Self>;
+}
+
+impl FromMetadataValue for MetadataValue {
+    fn from_metadata_value(value: &MetadataValue) -> Option<Self> {
+        Some(value.clone())
+    }
+}
+
+impl FromMetadataValue for String {
+    fn from_metadata_value(value: &MetadataValue) -> Option<Self> {
+        match value {
+            MetadataValue::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromMetadataValue for bool {
+    fn from_metadata_value(value: &MetadataValue) -> Option<Self> {
+        match value {
+            MetadataValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl FromMetadataValue for f64 {
+    fn from_metadata_value(value: &MetadataValue) -> Option<Self> {
+        match value {
+            MetadataValue::Float(value) => Some(*value),
+            MetadataValue::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_from_metadata_value_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl FromMetadataValue for $ty {
+                fn from_metadata_value(value: &MetadataValue) -> Option<Self> {
+                    match value {
+                        MetadataValue::Int(value) => <$ty>::try_from(*value).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_metadata_value_for_int!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// Key-value metadata attached to the provider or its method with `#[metadata(key = value)]`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metadata {
+    values: HashMap<String, MetadataValue>,
+}
+
+impl Metadata {
+    pub fn new() -> Metadata {
+        Metadata {
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn set<V: Into<MetadataValue>>(&mut self, key: &str, value: V) -> &mut Self {
+        self.values.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Returns the typed value, otherwise, `None` if the value is missed or has another type
+    pub fn get<T: FromMetadataValue>(&self, key: &str) -> Option<T> {
+        self.values.get(key).and_then(T::from_metadata_value)
+    }
+
+    pub fn get_value(&self, key: &str) -> Option<&MetadataValue> {
+        self.values.get(key)
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.values.keys().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn merge(&mut self, other: &Metadata) -> &mut Self {
+        for (key, value) in other.values.iter() {
+            self.values.insert(key.clone(), value.clone());
+        }
+        self
+    }
+}
+
+/// Metadata of the provider method collected by `#[implements]`
+pub struct MethodMetadataRecord {
+    pub provider_id: TypeId,
+    pub method: &'static str,
+    pub metadata: Metadata,
+}
+
+#[distributed_slice]
+#[linkme(crate = blackbox_cast::linkme)]
+pub static METHODS_METADATA: [fn() -> MethodMetadataRecord] = [..];
+
+/// Collects metadata of the provider methods by the method name
+pub fn get_methods_metadata<T: 'static>() -> HashMap<String, Metadata> {
+    let provider_id = TypeId::of::<T>();
+
+    let mut methods_metadata: HashMap<String, Metadata> = HashMap::new();
+
+    for record_fn in METHODS_METADATA.iter() {
+        let record = (record_fn)();
+
+        if record.provider_id != provider_id {
+            continue;
+        }
+
+        methods_metadata
+            .entry(record.method.to_string())
+            .or_default()
+            .merge(&record.metadata);
+    }
+
+    methods_metadata
+}
@@ -0,0 +1,204 @@
+use crate::cell::RefMut;
+
+type MatcherFn<TArgs> = Box<dyn Fn(&TArgs) -> bool + Send + Sync>;
+type ReturnFn<TArgs, TRet> = Box<dyn Fn(&TArgs) -> TRet + Send + Sync>;
+
+struct ExpectationState<TArgs, TRet> {
+    matcher: Option<MatcherFn<TArgs>>,
+    returning: Option<ReturnFn<TArgs, TRet>>,
+    times: Option<usize>,
+    call_count: usize,
+}
+
+/// Expected call of the mocked method, created by `MockIRepo::expect_<method>()`.
+///
+/// The arguments are passed as a tuple, the referenced arguments are owned.
+pub struct Expectation<TArgs, TRet> {
+    state: RefMut<ExpectationState<TArgs, TRet>>,
+}
+
+impl<TArgs, TRet> Clone for Expectation<TArgs, TRet> {
+    fn clone(&self) -> Self {
+        Expectation {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<TArgs, TRet> Expectation<TArgs, TRet> {
+    fn new() -> Expectation<TArgs, TRet> {
+        Expectation {
+            state: RefMut::new(ExpectationState {
+                matcher: None,
+                returning: None,
+                times: None,
+                call_count: 0,
+            }),
+        }
+    }
+
+    /// The expectation handles only the calls with the matching arguments
+    pub fn with<F>(&self, matcher: F) -> &Self
+    where
+        F: Fn(&TArgs) -> bool + Send + Sync + 'static,
+    {
+        self.state.as_mut().matcher = Some(Box::new(matcher));
+
+        return self;
+    }
+
+    pub fn returning<F>(&self, returning: F) -> &Self
+    where
+        F: Fn(&TArgs) -> TRet + Send + Sync + 'static,
+    {
+        self.state.as_mut().returning = Some(Box::new(returning));
+
+        return self;
+    }
+
+    pub fn return_const(&self, value: TRet) -> &Self
+    where
+        TRet: Clone + Send + Sync + 'static,
+    {
+        self.returning(move |_| value.clone())
+    }
+
+    /// The method must be called exactly `times` times, checked by `verify`
+    pub fn times(&self, times: usize) -> &Self {
+        self.state.as_mut().times = Some(times);
+
+        return self;
+    }
+
+    pub fn never(&self) -> &Self {
+        self.times(0)
+    }
+
+    pub fn get_call_count(&self) -> usize {
+        self.state.as_ref().call_count
+    }
+
+    fn matches(&self, args: &TArgs) -> bool {
+        match &self.state.as_ref().matcher {
+            Some(matcher) => (matcher)(args),
+            None => true,
+        }
+    }
+
+    fn is_saturated(&self) -> bool {
+        let state = self.state.as_ref();
+
+        match state.times {
+            Some(times) => state.call_count >= times,
+            None => false,
+        }
+    }
+}
+
+/// Mocked method of the `#[interface(mock)]` trait
+pub struct MockMethod<TArgs, TRet> {
+    name: String,
+    expectations: RefMut<Vec<Expectation<TArgs, TRet>>>,
+    default_returning: Option<ReturnFn<TArgs, TRet>>,
+    call_count: RefMut<usize>,
+}
+
+impl<TArgs, TRet> MockMethod<TArgs, TRet> {
+    pub fn new(name: &str) -> MockMethod<TArgs, TRet> {
+        MockMethod {
+            name: name.to_string(),
+            expectations: RefMut::new(Vec::new()),
+            default_returning: None,
+            call_count: RefMut::new(0),
+        }
+    }
+
+    /// Used if the expectation has no return value, e.g. for the methods returning `()`
+    pub fn with_default<F>(mut self, returning: F) -> MockMethod<TArgs, TRet>
+    where
+        F: Fn(&TArgs) -> TRet + Send + Sync + 'static,
+    {
+        self.default_returning = Some(Box::new(returning));
+        self
+    }
+
+    pub fn expect(&self) -> Expectation<TArgs, TRet> {
+        let expectation = Expectation::new();
+
+        self.expectations.as_mut().push(expectation.clone());
+
+        expectation
+    }
+
+    pub fn get_call_count(&self) -> usize {
+        *self.call_count.as_ref()
+    }
+
+    /// Removes the expectations and resets the call counter
+    pub fn reset(&self) {
+        self.expectations.as_mut().clear();
+        *self.call_count.as_mut() = 0;
+    }
+
+    pub fn call(&self, args: TArgs) -> TRet {
+        *self.call_count.as_mut() += 1;
+
+        // the returning fn can call the mock again, so the lock is not held
+        let expectations = self.expectations.as_ref().clone();
+
+        if expectations.is_empty() {
+            return self.call_default(&args);
+        }
+
+        let matching = expectations
+            .iter()
+            .filter(|expectation| expectation.matches(&args))
+            .collect::<Vec<_>>();
+
+        if matching.is_empty() {
+            panic!("{}: no expectation matches the arguments", self.name);
+        }
+
+        let expectation = matching
+            .iter()
+            .find(|expectation| !expectation.is_saturated())
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}: the method is called more times than expected",
+                    self.name
+                )
+            });
+
+        expectation.state.as_mut().call_count += 1;
+
+        let state = expectation.state.as_ref();
+
+        match &state.returning {
+            Some(returning) => (returning)(&args),
+            None => self.call_default(&args),
+        }
+    }
+
+    /// Panics if an expectation with `times` is not satisfied
+    pub fn verify(&self) {
+        for expectation in self.expectations.as_ref().iter() {
+            let state = expectation.state.as_ref();
+
+            if let Some(times) = state.times {
+                if state.call_count != times {
+                    panic!(
+                        "{}: expected {} calls, but the method was called {} times",
+                        self.name, times, state.call_count
+                    );
+                }
+            }
+        }
+    }
+
+    fn call_default(&self, args: &TArgs) -> TRet {
+        match &self.default_returning {
+            Some(returning) => (returning)(args),
+            None => panic!("{}: no return value is set", self.name),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use blackbox_cast::CastFrom;
+
+use crate::{
+    container::Container,
+    instance_wrapper::{InstanceToken, InstanceWrapper, Scope},
+    module::Module,
+    reference::Ref,
+    reference_mut::RefMut,
+    resolver::resolve_instance,
+    tokens::get_token,
+};
+
+/// Lookup of the providers bound to the module.
+///
+/// Non-strict lookup follows the `Container::get_provider_in_module` rules,
+/// strict lookup is limited by the providers of the module.
+#[derive(Clone)]
+pub struct ModuleContext {
+    container: RefMut<Container>,
+    module: RefMut<Module>,
+    strict: bool,
+}
+
+impl ModuleContext {
+    pub fn new(container: RefMut<Container>, module: RefMut<Module>) -> ModuleContext {
+        ModuleContext {
+            container,
+            module,
+            strict: false,
+        }
+    }
+
+    /// Returns the same context with the strict lookup
+    pub fn strict(&self) -> ModuleContext {
+        ModuleContext {
+            container: self.container.clone(),
+            module: self.module.clone(),
+            strict: true,
+        }
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn get_module(&self) -> RefMut<Module> {
+        self.module.clone()
+    }
+
+    /// Retrieves an instance of either injectable, otherwise, throws error.
+    pub fn get<TInjectable: CastFrom>(&self) -> Result<Ref<TInjectable>, String> {
+        self.get_by_token(&get_token::<TInjectable>())
+    }
+
+    /// Retrieves an instance of either injectable by token, otherwise, throws error.
+    ///
+    /// Transient providers have no shared instance, use `resolve` for them.
+    pub fn get_by_token<TInjectable: ?Sized + CastFrom>(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<Ref<TInjectable>, String> {
+        let wrapper = self.find_provider(token)?;
+
+        if wrapper.as_ref().get_scope() == Scope::Transient {
+            return Err(format!(
+                "ModuleContext: {} provider is transient, use resolve() to create its instance.",
+                token
+            ));
+        }
+
+        let instance = wrapper
+            .as_ref()
+            .get_instance()
+            .ok_or_else(|| format!("ModuleContext: {} static instance was not found.", token))?;
+
+        instance
+            .cast::<TInjectable>()
+            .map_err(|err| err.to_string())
+    }
+
+    /// Creates a new instance of either transient injectable, otherwise, returns the shared instance.
+    pub async fn resolve<TInjectable: CastFrom>(&self) -> Result<Ref<TInjectable>, String> {
+        self.resolve_by_token(&get_token::<TInjectable>()).await
+    }
+
+    /// Creates a new instance of either transient injectable by token, otherwise, returns the shared instance.
+    pub async fn resolve_by_token<TInjectable: ?Sized + CastFrom>(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<Ref<TInjectable>, String> {
+        let wrapper = self.find_provider(token)?;
+
+        let instance = resolve_instance(self.container.clone(), wrapper).await?;
+
+        instance
+            .cast::<TInjectable>()
+            .map_err(|err| err.to_string())
+    }
+
+    fn find_provider(&self, token: &InstanceToken) -> Result<RefMut<InstanceWrapper>, String> {
+        let provider = if self.strict {
+            self.module.as_ref().get_provider(token)
+        } else {
+            self.container
+                .as_ref()
+                .get_provider_in_module(token, self.module.clone())
+        };
+
+        provider.ok_or_else(|| {
+            format!(
+                "ModuleContext: {} provider was not found in the {} module.",
+                token,
+                self.module.as_ref().get_token()
+            )
+        })
+    }
+}
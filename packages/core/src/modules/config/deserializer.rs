@@ -0,0 +1,141 @@
+use serde::{
+    de::{
+        value::{MapDeserializer, SeqDeserializer},
+        DeserializeOwned, Error as _, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any, Deserializer,
+};
+use serde_json::{Error, Value};
+
+/// Deserializes the config value into the type.
+///
+/// Values of the environment variables are strings, so numbers and bools are parsed from strings.
+pub(crate) fn deserialize_value<T: DeserializeOwned>(value: &Value) -> Result<T, String> {
+    T::deserialize(ValueDeserializer(value)).map_err(|err| err.to_string())
+}
+
+struct ValueDeserializer<'a>(&'a Value);
+
+impl<'a> ValueDeserializer<'a> {
+    fn parse<'de, T: std::str::FromStr, V: Visitor<'de>>(
+        self,
+        visitor: V,
+        visit: fn(V, T) -> Result<V::Value, Error>,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::String(value) => match value.trim().parse::<T>() {
+                Ok(value) => visit(visitor, value),
+                Err(_) => Err(Error::custom(format!(
+                    "invalid value: \"{}\", expected {}",
+                    value,
+                    std::any::type_name::<T>()
+                ))),
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+}
+
+impl<'de, 'a> IntoDeserializer<'de, Error> for ValueDeserializer<'a> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $ty:ty, $visit:ident;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.parse::<$ty, V>(visitor, |visitor, value| visitor.$visit(value))
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for ValueDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(value) => {
+                if let Some(value) = value.as_u64() {
+                    visitor.visit_u64(value)
+                } else if let Some(value) = value.as_i64() {
+                    visitor.visit_i64(value)
+                } else {
+                    visitor.visit_f64(value.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(value) => visitor.visit_str(value),
+            Value::Array(items) => {
+                visitor.visit_seq(SeqDeserializer::new(items.iter().map(ValueDeserializer)))
+            }
+            Value::Object(map) => visitor.visit_map(MapDeserializer::new(
+                map.iter()
+                    .map(|(key, value)| (key.as_str(), ValueDeserializer(value))),
+            )),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => bool, visit_bool;
+        deserialize_i8 => i64, visit_i64;
+        deserialize_i16 => i64, visit_i64;
+        deserialize_i32 => i64, visit_i64;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_u8 => u64, visit_u64;
+        deserialize_u16 => u64, visit_u64;
+        deserialize_u32 => u64, visit_u64;
+        deserialize_u64 => u64, visit_u64;
+        deserialize_f32 => f64, visit_f64;
+        deserialize_f64 => f64, visit_f64;
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Bool(value) => visitor.visit_string(value.to_string()),
+            Value::Number(value) => visitor.visit_string(value.to_string()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::String(value) => visitor.visit_enum(value.as_str().into_deserializer()),
+            value => value.clone().deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
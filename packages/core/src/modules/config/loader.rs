@@ -0,0 +1,146 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use serde_json::{Map, Value};
+
+use super::options::ConfigOptions;
+
+const ENV_PREFIX_DELIMITER: &str = "_";
+const ENV_KEY_DELIMITER: &str = "__";
+
+/// Loads the configuration tree from the sources of the options
+pub(crate) fn load_config(options: &ConfigOptions) -> Result<Value, String> {
+    let mut config = Value::Object(Map::new());
+
+    for file in options.files.iter() {
+        merge_values(&mut config, read_file(file)?);
+    }
+
+    // the keys of the env file are added like the keys of the config files
+    if let Some(env_file) = &options.env_file {
+        for (key, value) in read_env_file(env_file)? {
+            if let Some(path) = get_env_key_path(&key, &options.prefix) {
+                set_env_value(&mut config, &path, value, true);
+            }
+        }
+    }
+
+    // without the prefix the process variables override only the existing keys
+    let can_add_keys = options.prefix.is_some();
+
+    for (key, value) in std::env::vars() {
+        if let Some(path) = get_env_key_path(&key, &options.prefix) {
+            set_env_value(&mut config, &path, value, can_add_keys);
+        }
+    }
+
+    Ok(config)
+}
+
+/// Returns the value by the dotted key, e.g. `db.port`. The empty key returns the whole tree
+pub(crate) fn get_value<'a>(config: &'a Value, key: &str) -> Option<&'a Value> {
+    if key.is_empty() {
+        return Some(config);
+    }
+
+    key.split('.')
+        .try_fold(config, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get(index)),
+            _ => None,
+        })
+}
+
+fn read_file(file: &str) -> Result<Value, String> {
+    let content = fs::read_to_string(file)
+        .map_err(|err| format!("Failed to read the {} config file: {}", file, err))?;
+
+    let extension = Path::new(file)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+
+    match extension {
+        "toml" => toml::from_str::<Value>(&content).map_err(|err| err.to_string()),
+        "json" => serde_json::from_str::<Value>(&content).map_err(|err| err.to_string()),
+        _ => return Err(format!("The {} config file has unsupported format", file)),
+    }
+    .map_err(|err| format!("Failed to parse the {} config file: {}", file, err))
+}
+
+fn read_env_file(env_file: &str) -> Result<Vec<(String, String)>, String> {
+    let iter = match dotenvy::from_path_iter(env_file) {
+        Ok(iter) => iter,
+        Err(dotenvy::Error::Io(err)) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("Failed to read the {} env file: {}", env_file, err)),
+    };
+
+    iter.collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("Failed to parse the {} env file: {}", env_file, err))
+}
+
+/// `APP_DB__PORT` => `["db", "port"]` for the `APP` prefix
+fn get_env_key_path(key: &str, prefix: &Option<String>) -> Option<Vec<String>> {
+    let key = match prefix {
+        Some(prefix) => key.strip_prefix(&format!("{}{}", prefix, ENV_PREFIX_DELIMITER))?,
+        None => key,
+    };
+
+    if key.is_empty() {
+        return None;
+    }
+
+    Some(
+        key.to_lowercase()
+            .split(ENV_KEY_DELIMITER)
+            .map(String::from)
+            .collect(),
+    )
+}
+
+/// Environment variables override only leaf values, the sections and the values of the files
+/// are not replaced by the new sections
+fn set_env_value(config: &mut Value, path: &[String], value: String, can_add_keys: bool) {
+    let mut target = config;
+
+    for (index, segment) in path.iter().enumerate() {
+        let section = match target {
+            Value::Object(section) => section,
+            _ => return,
+        };
+
+        if !can_add_keys && !section.contains_key(segment) {
+            return;
+        }
+
+        target = section.entry(segment.clone()).or_insert_with(|| {
+            if index + 1 < path.len() {
+                Value::Object(Map::new())
+            } else {
+                Value::Null
+            }
+        });
+    }
+
+    if !target.is_object() {
+        *target = Value::String(value);
+    }
+}
+
+fn merge_values(target: &mut Value, source: Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(target_value) => merge_values(target_value, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
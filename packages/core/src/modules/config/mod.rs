@@ -0,0 +1,78 @@
+mod deserializer;
+mod loader;
+mod options;
+mod service;
+
+use blackbox_cast::CastFrom;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    builder::ProviderBuilder, cell::Ref, dynamic_module::DynamicModule, injectable::IInjectable,
+    tokens::get_token,
+};
+
+pub use self::options::ConfigOptions;
+pub use self::service::ConfigService;
+
+pub type ConfigValidateFn<T> = fn(&T) -> Result<(), String>;
+
+/// Global module exposing the `ConfigService` and the values for the `#[config]` providers
+pub struct ConfigModule {}
+
+impl ConfigModule {
+    pub fn for_root(options: ConfigOptions) -> DynamicModule {
+        DynamicModule::new::<ConfigModule, _>(move |module_builder| {
+            let config_service = ConfigService::load(&options)?;
+
+            module_builder.should_be_global();
+            module_builder
+                .register_value(
+                    get_token::<ConfigService>(),
+                    Ref::new(config_service).cast::<dyn IInjectable>().unwrap(),
+                )
+                .should_be_exported();
+
+            Ok(())
+        })
+    }
+}
+
+/// Loads the instance of the `#[config]` provider from the `ConfigService`
+pub fn register_config<T: DeserializeOwned + CastFrom>(
+    provider_builder: Ref<ProviderBuilder>,
+    prefix: &str,
+    validate_fn: Option<ConfigValidateFn<T>>,
+) {
+    let token = provider_builder.instance_wrapper.as_ref().get_token();
+
+    let config_service = provider_builder
+        .context
+        .as_ref()
+        .container
+        .as_ref()
+        .get_provider(&get_token::<ConfigService>())
+        .and_then(|wrapper| wrapper.as_ref().get_instance())
+        .and_then(|instance| instance.cast::<ConfigService>().ok())
+        .unwrap_or_else(|| {
+            panic!(
+                "The {} config requires ConfigModule::for_root() in the build params",
+                token
+            )
+        });
+
+    let config = config_service
+        .get_section::<T>(prefix)
+        .and_then(|config| {
+            if let Some(validate_fn) = validate_fn {
+                (validate_fn)(&config)?;
+            }
+
+            Ok(config)
+        })
+        .unwrap_or_else(|err| panic!("The {} config is invalid: {}", token, err));
+
+    provider_builder
+        .instance_wrapper
+        .as_mut()
+        .set_instance(Ref::new(config).cast::<dyn IInjectable>().unwrap());
+}
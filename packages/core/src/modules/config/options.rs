@@ -0,0 +1,15 @@
+/// Sources of the configuration.
+///
+/// Values of the files are merged in order, then overridden by the `.env` file
+/// and the environment variables.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOptions {
+    /// Path to the `.env` file, a missed file is ignored
+    pub env_file: Option<String>,
+    /// Paths to the TOML or JSON files
+    pub files: Vec<String>,
+    /// Prefix of the environment variables, e.g. `APP` for `APP_DB__PORT`.
+    /// Without the prefix the process environment variables override only the keys of the files
+    /// and the `.env` file
+    pub prefix: Option<String>,
+}
@@ -0,0 +1,59 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{implements, injectable};
+
+use super::{
+    deserializer::deserialize_value,
+    loader::{get_value, load_config},
+    options::ConfigOptions,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+pub struct ConfigService {
+    values: Value,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ConfigService {
+    #[factory]
+    pub fn new() -> ConfigService {
+        ConfigService {
+            values: Value::Object(Map::new()),
+        }
+    }
+
+    pub(crate) fn load(options: &ConfigOptions) -> Result<ConfigService, String> {
+        Ok(ConfigService {
+            values: load_config(options)?,
+        })
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        get_value(&self.values, key).is_some()
+    }
+
+    /// Retrieves the typed value by the dotted key, e.g. `db.port`
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T, String> {
+        let value = get_value(&self.values, key)
+            .ok_or_else(|| format!("ConfigService: {} key was not found.", key))?;
+
+        deserialize_value(value)
+            .map_err(|err| format!("ConfigService: {} key has invalid value: {}.", key, err))
+    }
+
+    /// Retrieves the typed value by the dotted key, otherwise, returns the default value
+    pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Deserializes the section by the prefix, the missed section is considered empty
+    pub(crate) fn get_section<T: DeserializeOwned>(&self, prefix: &str) -> Result<T, String> {
+        let empty = Value::Object(Map::new());
+        let value = get_value(&self.values, prefix).unwrap_or(&empty);
+
+        deserialize_value(value)
+    }
+}
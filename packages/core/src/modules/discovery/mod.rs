@@ -0,0 +1,15 @@
+mod provider_info;
+mod service;
+
+use crate::module;
+
+pub use self::provider_info::ProviderInfo;
+pub use self::service::DiscoveryService;
+
+#[module]
+#[blackbox_di(crate)]
+pub struct DiscoveryModule {
+    #[provider]
+    #[export]
+    pub discovery_service: DiscoveryService,
+}
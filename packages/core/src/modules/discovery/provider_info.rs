@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use blackbox_cast::{CastFrom, CasterRegistry};
+
+use crate::{
+    cell::{Ref, RefMut},
+    instance_wrapper::{Instance, InstanceToken, InstanceWrapper, Scope},
+    metadata::Metadata,
+    module::{Module, ModuleToken},
+};
+
+/// Describes a provider registered in the container
+#[derive(Clone)]
+pub struct ProviderInfo {
+    wrapper: RefMut<InstanceWrapper>,
+}
+
+impl ProviderInfo {
+    pub fn new(wrapper: RefMut<InstanceWrapper>) -> ProviderInfo {
+        ProviderInfo { wrapper }
+    }
+
+    pub fn get_token(&self) -> InstanceToken {
+        self.wrapper.as_ref().get_token()
+    }
+
+    pub fn get_host(&self) -> RefMut<Module> {
+        self.wrapper.as_ref().get_host()
+    }
+
+    pub fn get_host_token(&self) -> ModuleToken {
+        self.get_host().as_ref().get_token()
+    }
+
+    pub fn get_scope(&self) -> Scope {
+        self.wrapper.as_ref().get_scope()
+    }
+
+    /// Returns metadata specified with `#[metadata(...)]` on the provider
+    pub fn get_metadata(&self) -> Metadata {
+        self.wrapper.as_ref().get_metadata()
+    }
+
+    /// Returns metadata specified with `#[metadata(...)]` on the provider method
+    pub fn get_method_metadata(&self, method: &str) -> Option<Metadata> {
+        self.wrapper.as_ref().get_method_metadata(method)
+    }
+
+    pub fn get_methods_metadata(&self) -> HashMap<String, Metadata> {
+        self.wrapper.as_ref().get_methods_metadata()
+    }
+
+    pub fn get_wrapper(&self) -> RefMut<InstanceWrapper> {
+        self.wrapper.clone()
+    }
+
+    /// Returns the shared instance of the provider. Transient providers have no shared instance.
+    pub fn get_instance(&self) -> Option<Instance> {
+        if self.get_scope() == Scope::Transient {
+            return None;
+        }
+
+        self.wrapper.as_ref().get_instance()
+    }
+
+    /// Returns all instances of the provider created by the container
+    pub fn get_instances(&self) -> Vec<Instance> {
+        self.wrapper.as_ref().get_instances()
+    }
+
+    /// Returns the shared instance of the provider casted to the type
+    pub fn get_instance_as<T: ?Sized + CastFrom>(&self) -> Option<Ref<T>> {
+        self.get_instance()
+            .and_then(|instance| instance.cast::<T>().ok())
+    }
+
+    /// Checks the provider can be casted to the type by its type or its instances,
+    /// so the transient providers without instances are found too
+    pub fn is<T: ?Sized + CastFrom>(&self) -> bool {
+        let type_id = self.wrapper.as_ref().get_type_id();
+
+        if let Some(type_id) = type_id {
+            if CasterRegistry::global().get::<T>(type_id).is_some() {
+                return true;
+            }
+        }
+
+        self.get_instances()
+            .iter()
+            .any(|instance| instance.is::<T>())
+    }
+}
@@ -0,0 +1,106 @@
+use blackbox_cast::CastFrom;
+
+use crate::{
+    cell::RefMut, container::Container, implements, injectable, inquirer::Inquirer,
+    instance_wrapper::Instance, metadata::Metadata, module::ModuleToken,
+    resolver::resolve_instance,
+};
+
+use super::provider_info::ProviderInfo;
+
+/// Lists the providers of the container, the container is held weakly by the inquirer
+#[injectable]
+#[blackbox_di(crate)]
+pub struct DiscoveryService {
+    #[inquirer]
+    inquirer: Inquirer,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl DiscoveryService {
+    fn get_container(&self) -> Result<RefMut<Container>, String> {
+        self.inquirer
+            .get_container()
+            .map_err(|err| format!("DiscoveryService: {}", err))
+    }
+
+    /// Returns all providers of the container. Deeper modules go first.
+    ///
+    /// The list is empty if the app is already dropped.
+    pub fn get_providers(&self) -> Vec<ProviderInfo> {
+        let container = match self.get_container() {
+            Ok(container) => container,
+            Err(_) => return Vec::new(),
+        };
+
+        let modules = container.as_ref().get_modules_sorted_by_distance();
+
+        let mut providers: Vec<ProviderInfo> = Vec::new();
+
+        for module in modules {
+            let mut module_providers = module
+                .as_ref()
+                .get_providers()
+                .into_values()
+                .map(ProviderInfo::new)
+                .collect::<Vec<_>>();
+
+            module_providers.sort_by_key(|provider| provider.get_token());
+
+            providers.append(&mut module_providers);
+        }
+
+        providers
+    }
+
+    /// Returns providers of the module
+    pub fn get_providers_by_module(&self, token: &ModuleToken) -> Vec<ProviderInfo> {
+        self.get_providers()
+            .into_iter()
+            .filter(|provider| &provider.get_host_token() == token)
+            .collect()
+    }
+
+    /// Returns providers having the metadata key
+    pub fn get_providers_with_metadata(&self, key: &str) -> Vec<ProviderInfo> {
+        self.get_providers()
+            .into_iter()
+            .filter(|provider| provider.get_metadata().has(key))
+            .collect()
+    }
+
+    /// Returns provider methods having the metadata key
+    pub fn get_methods_with_metadata(&self, key: &str) -> Vec<(ProviderInfo, String, Metadata)> {
+        let mut methods: Vec<(ProviderInfo, String, Metadata)> = Vec::new();
+
+        for provider in self.get_providers() {
+            let mut provider_methods = provider
+                .get_methods_metadata()
+                .into_iter()
+                .filter(|(_method, metadata)| metadata.has(key))
+                .collect::<Vec<_>>();
+
+            provider_methods.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            for (method, metadata) in provider_methods {
+                methods.push((provider.clone(), method, metadata));
+            }
+        }
+
+        methods
+    }
+
+    /// Resolves an instance of the provider, the transient providers get a new instance
+    pub async fn resolve(&self, provider: &ProviderInfo) -> Result<Instance, String> {
+        resolve_instance(self.get_container()?, provider.get_wrapper()).await
+    }
+
+    /// Returns providers which instances can be casted to the type, e.g. `dyn IJobHandler`
+    pub fn get_providers_implementing<T: ?Sized + CastFrom>(&self) -> Vec<ProviderInfo> {
+        self.get_providers()
+            .into_iter()
+            .filter(|provider| provider.is::<T>())
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
+
+use crate::{
+    cell::{Ref, RefMut},
+    container::Container,
+    implements, injectable,
+    instance_wrapper::{Instance, Scope},
+    tokens::get_token,
+    ILogger, Logger,
+};
+
+use super::{
+    event::Event,
+    handler::{EventHandlerFn, EVENT_HANDLERS},
+    topic::is_topic_matched,
+};
+
+const EVENT_EMITTER_CONTEXT: &str = "EventEmitter";
+
+#[derive(Clone)]
+struct Listener {
+    pattern: String,
+    name: String,
+    instance: Instance,
+    handler: EventHandlerFn,
+}
+
+/// The error of the event handler
+#[derive(Debug, Clone)]
+pub struct EventHandlerError {
+    pub topic: String,
+    pub handler: String,
+    pub msg: String,
+}
+
+impl std::fmt::Display for EventHandlerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "The {} handler of the {} event failed: {}",
+            self.handler, self.topic, self.msg
+        )
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+pub struct EventEmitter {
+    #[inject]
+    logger: Ref<Logger>,
+
+    listeners: RefMut<Vec<Listener>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl EventEmitter {
+    #[factory]
+    pub fn new(logger: Ref<Logger>) -> EventEmitter {
+        EventEmitter {
+            logger,
+            listeners: RefMut::new(Vec::new()),
+        }
+    }
+
+    /// Subscribes the `#[on_event]` handlers of the provider instance, the instance is subscribed only once
+    pub fn subscribe(&self, instance: Instance) {
+        let provider_id = (*instance.as_ref()).type_id();
+
+        if provider_id == TypeId::of::<EventEmitter>() || self.is_subscribed(&instance) {
+            return;
+        }
+
+        for record_fn in EVENT_HANDLERS.iter() {
+            let record = (record_fn)();
+
+            if record.provider_id != provider_id {
+                continue;
+            }
+
+            self.listeners.as_mut().push(Listener {
+                pattern: record.topic.to_string(),
+                name: format!("{}::{}", record.provider, record.method),
+                instance: instance.clone(),
+                handler: record.handler,
+            });
+        }
+    }
+
+    fn is_subscribed(&self, instance: &Instance) -> bool {
+        self.listeners
+            .as_ref()
+            .iter()
+            .any(|listener| Arc::ptr_eq(&listener.instance.as_ref(), &instance.as_ref()))
+    }
+
+    pub fn has_listeners(&self, topic: &str) -> bool {
+        !self.get_listeners(topic).is_empty()
+    }
+
+    /// Emits the event in the background and returns the number of the notified handlers.
+    ///
+    /// Errors of the handlers are logged.
+    pub fn emit<P: Any + Send + Sync>(&self, topic: &str, payload: P) -> usize {
+        let listeners = self.get_listeners(topic);
+        let count = listeners.len();
+
+        if count == 0 {
+            return 0;
+        }
+
+        let event = Event::new(topic, payload);
+        let logger = self.logger.clone();
+
+        tokio::spawn(async move {
+            if let Err(errors) = run_listeners(listeners, event).await {
+                for error in errors {
+                    logger.error_with_ctx(error.to_string().as_str(), EVENT_EMITTER_CONTEXT);
+                }
+            }
+        });
+
+        count
+    }
+
+    /// Emits the event and waits for all handlers.
+    ///
+    /// Every handler runs in isolation, so failed handlers don't affect others.
+    pub async fn emit_async<P: Any + Send + Sync>(
+        &self,
+        topic: &str,
+        payload: P,
+    ) -> Result<(), Vec<EventHandlerError>> {
+        let listeners = self.get_listeners(topic);
+
+        run_listeners(listeners, Event::new(topic, payload)).await
+    }
+
+    fn get_listeners(&self, topic: &str) -> Vec<Listener> {
+        self.listeners
+            .as_ref()
+            .iter()
+            .filter(|listener| is_topic_matched(&listener.pattern, topic))
+            .cloned()
+            .collect()
+    }
+}
+
+async fn run_listeners(
+    listeners: Vec<Listener>,
+    event: Event,
+) -> Result<(), Vec<EventHandlerError>> {
+    let handles = listeners
+        .into_iter()
+        .map(|listener| {
+            let future = (listener.handler)(listener.instance.clone(), event.clone());
+
+            (listener.name, tokio::spawn(future))
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors: Vec<EventHandlerError> = Vec::new();
+
+    for (name, handle) in handles {
+        let msg = match handle.await {
+            Ok(Ok(())) => continue,
+            Ok(Err(msg)) => msg,
+            Err(err) => err.to_string(),
+        };
+
+        errors.push(EventHandlerError {
+            topic: event.get_topic(),
+            handler: name,
+            msg,
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Subscribes handlers of the singleton instances to the event emitter if it's registered.
+///
+/// The transient instances are not subscribed, the listeners would keep them alive for the life of the app.
+pub(crate) fn subscribe_event_handlers(container: &RefMut<Container>) {
+    let event_emitter = container
+        .as_ref()
+        .get_provider(&get_token::<EventEmitter>())
+        .and_then(|wrapper| wrapper.as_ref().get_instance())
+        .and_then(|instance| instance.cast::<EventEmitter>().ok());
+
+    let event_emitter = match event_emitter {
+        Some(event_emitter) => event_emitter,
+        None => return,
+    };
+
+    for module in container.as_ref().get_modules_sorted_by_distance() {
+        for (_token, provider) in module.as_ref().get_providers() {
+            let provider = provider.as_ref();
+
+            if provider.get_scope() != Scope::Singleton {
+                continue;
+            }
+
+            if let Some(instance) = provider.get_instance() {
+                event_emitter.subscribe(instance);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::{any::Any, sync::Arc};
+
+/// The emitted event passed to the handlers
+#[derive(Clone)]
+pub struct Event {
+    topic: String,
+    payload: Arc<dyn Any + Send + Sync>,
+}
+
+impl Event {
+    pub fn new<P: Any + Send + Sync>(topic: &str, payload: P) -> Event {
+        Event {
+            topic: topic.to_string(),
+            payload: Arc::new(payload),
+        }
+    }
+
+    pub fn get_topic(&self) -> String {
+        self.topic.clone()
+    }
+
+    /// Returns the payload of the type, the `Event` type returns the event itself
+    pub fn get_payload<P: Any + Send + Sync>(&self) -> Option<Arc<P>> {
+        if let Ok(payload) = self.payload.clone().downcast::<P>() {
+            return Some(payload);
+        }
+
+        let event: Arc<dyn Any + Send + Sync> = Arc::new(self.clone());
+
+        event.downcast::<P>().ok()
+    }
+}
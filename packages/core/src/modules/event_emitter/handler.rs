@@ -0,0 +1,40 @@
+use std::{any::TypeId, fmt::Display, future::Future, pin::Pin};
+
+use blackbox_cast::linkme::distributed_slice;
+
+use crate::instance_wrapper::Instance;
+
+use super::event::Event;
+
+pub type EventHandlerFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+pub type EventHandlerFn = fn(instance: Instance, event: Event) -> EventHandlerFuture;
+
+/// Handler of the provider method annotated with `#[on_event("topic")]`
+pub struct EventHandlerRecord {
+    pub provider_id: TypeId,
+    pub provider: &'static str,
+    pub method: &'static str,
+    pub topic: &'static str,
+    pub handler: EventHandlerFn,
+}
+
+#[distributed_slice]
+#[linkme(crate = blackbox_cast::linkme)]
+pub static EVENT_HANDLERS: [fn() -> EventHandlerRecord] = [..];
+
+/// Conversion of the handler result, handlers can return either `()` or `Result<(), E>`
+pub trait IntoEventResult {
+    fn into_event_result(self) -> Result<(), String>;
+}
+
+impl IntoEventResult for () {
+    fn into_event_result(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<E: Display> IntoEventResult for Result<(), E> {
+    fn into_event_result(self) -> Result<(), String> {
+        self.map_err(|err| err.to_string())
+    }
+}
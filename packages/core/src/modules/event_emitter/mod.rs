@@ -0,0 +1,23 @@
+mod emitter;
+mod event;
+mod handler;
+mod topic;
+
+use crate::module;
+
+pub(crate) use self::emitter::subscribe_event_handlers;
+pub use self::emitter::{EventEmitter, EventHandlerError};
+pub use self::event::Event;
+pub use self::handler::{
+    EventHandlerFn, EventHandlerFuture, EventHandlerRecord, IntoEventResult, EVENT_HANDLERS,
+};
+pub use self::topic::is_topic_matched;
+
+#[module]
+#[global]
+#[blackbox_di(crate)]
+pub struct EventEmitterModule {
+    #[provider]
+    #[export]
+    pub event_emitter: EventEmitter,
+}
@@ -0,0 +1,35 @@
+const TOPIC_DELIMITER: char = '.';
+const SINGLE_SEGMENT_WILDCARD: &str = "*";
+const MULTI_SEGMENT_WILDCARD: &str = "**";
+
+/// Matches the topic with the pattern.
+///
+/// Examples:
+///
+/// user.created => user.created
+/// user.* => user.created, user.deleted
+/// user.** => user.created, user.profile.updated
+/// ** => any topic
+pub fn is_topic_matched(pattern: &str, topic: &str) -> bool {
+    let pattern = pattern.split(TOPIC_DELIMITER).collect::<Vec<_>>();
+    let topic = topic.split(TOPIC_DELIMITER).collect::<Vec<_>>();
+
+    is_segments_matched(&pattern, &topic)
+}
+
+fn is_segments_matched(pattern: &[&str], topic: &[&str]) -> bool {
+    match (pattern.first(), topic.first()) {
+        (None, None) => true,
+        (Some(&MULTI_SEGMENT_WILDCARD), _) => {
+            is_segments_matched(&pattern[1..], topic)
+                || (!topic.is_empty() && is_segments_matched(pattern, &topic[1..]))
+        }
+        (Some(&SINGLE_SEGMENT_WILDCARD), Some(_)) => {
+            is_segments_matched(&pattern[1..], &topic[1..])
+        }
+        (Some(pattern_segment), Some(topic_segment)) => {
+            pattern_segment == topic_segment && is_segments_matched(&pattern[1..], &topic[1..])
+        }
+        _ => false,
+    }
+}
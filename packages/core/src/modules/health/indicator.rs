@@ -0,0 +1,95 @@
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
+
+use serde_json::Value;
+
+use crate::interface;
+
+pub const DEFAULT_HEALTH_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthStatus {
+    Up,
+    Degraded,
+    Down,
+}
+
+impl HealthStatus {
+    /// HTTP status code of the status, degraded service is still available
+    pub fn get_http_status_code(&self) -> u16 {
+        match self {
+            HealthStatus::Up | HealthStatus::Degraded => 200,
+            HealthStatus::Down => 503,
+        }
+    }
+}
+
+impl Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HealthStatus::Up => write!(f, "up"),
+            HealthStatus::Degraded => write!(f, "degraded"),
+            HealthStatus::Down => write!(f, "down"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthIndicatorResult {
+    pub status: HealthStatus,
+    pub details: BTreeMap<String, Value>,
+}
+
+impl HealthIndicatorResult {
+    pub fn new(status: HealthStatus) -> HealthIndicatorResult {
+        HealthIndicatorResult {
+            status,
+            details: BTreeMap::new(),
+        }
+    }
+
+    pub fn up() -> HealthIndicatorResult {
+        HealthIndicatorResult::new(HealthStatus::Up)
+    }
+
+    pub fn degraded() -> HealthIndicatorResult {
+        HealthIndicatorResult::new(HealthStatus::Degraded)
+    }
+
+    pub fn down() -> HealthIndicatorResult {
+        HealthIndicatorResult::new(HealthStatus::Down)
+    }
+
+    pub fn with_detail<V: Into<Value>>(mut self, key: &str, value: V) -> Self {
+        self.details.insert(key.to_string(), value.into());
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        let mut json = serde_json::Map::new();
+
+        json.insert(String::from("status"), Value::from(self.status.to_string()));
+
+        if !self.details.is_empty() {
+            json.insert(
+                String::from("details"),
+                Value::Object(self.details.clone().into_iter().collect()),
+            );
+        }
+
+        Value::Object(json)
+    }
+}
+
+/// Health indicator discovered by the `HealthService` among the providers
+#[interface]
+#[blackbox_di(crate)]
+pub trait IHealthIndicator {
+    fn get_name(&self) -> String;
+
+    async fn check(&self) -> HealthIndicatorResult;
+
+    /// The indicator is considered down if the check takes longer
+    fn get_timeout(&self) -> Duration {
+        DEFAULT_HEALTH_TIMEOUT
+    }
+}
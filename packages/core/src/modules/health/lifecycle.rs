@@ -0,0 +1,83 @@
+use std::fmt::Display;
+
+use crate::{
+    cell::RefMut,
+    events::{OnApplicationBootstrap, OnModuleDestroy},
+    implements, injectable,
+};
+
+use super::indicator::{HealthIndicatorResult, IHealthIndicator};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleState {
+    Starting,
+    Running,
+    ShuttingDown,
+}
+
+impl Display for LifecycleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LifecycleState::Starting => write!(f, "starting"),
+            LifecycleState::Running => write!(f, "running"),
+            LifecycleState::ShuttingDown => write!(f, "shutting_down"),
+        }
+    }
+}
+
+/// Reports the app is up only while it's running
+#[injectable]
+#[blackbox_di(crate)]
+pub struct LifecycleHealthIndicator {
+    state: RefMut<LifecycleState>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl LifecycleHealthIndicator {
+    #[factory]
+    pub fn new() -> LifecycleHealthIndicator {
+        LifecycleHealthIndicator {
+            state: RefMut::new(LifecycleState::Starting),
+        }
+    }
+
+    pub fn get_state(&self) -> LifecycleState {
+        *self.state.as_ref()
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for LifecycleHealthIndicator {
+    fn get_name(&self) -> String {
+        String::from("lifecycle")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        let state = self.get_state();
+
+        let result = match state {
+            LifecycleState::Running => HealthIndicatorResult::up(),
+            _ => HealthIndicatorResult::down(),
+        };
+
+        result.with_detail("state", state.to_string())
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnApplicationBootstrap for LifecycleHealthIndicator {
+    async fn on_application_bootstrap(&self) {
+        *self.state.as_mut() = LifecycleState::Running;
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnModuleDestroy for LifecycleHealthIndicator {
+    async fn on_module_destroy(&self) {
+        *self.state.as_mut() = LifecycleState::ShuttingDown;
+    }
+}
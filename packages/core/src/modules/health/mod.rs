@@ -0,0 +1,23 @@
+mod indicator;
+mod lifecycle;
+mod service;
+
+use crate::module;
+
+pub use self::indicator::{
+    HealthIndicatorResult, HealthStatus, IHealthIndicator, DEFAULT_HEALTH_TIMEOUT,
+};
+pub use self::lifecycle::{LifecycleHealthIndicator, LifecycleState};
+pub use self::service::{HealthReport, HealthService};
+
+#[module]
+#[blackbox_di(crate)]
+pub struct HealthModule {
+    #[provider]
+    #[export]
+    pub health_service: HealthService,
+
+    #[provider]
+    #[export]
+    pub lifecycle_health_indicator: LifecycleHealthIndicator,
+}
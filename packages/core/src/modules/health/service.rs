@@ -0,0 +1,172 @@
+use std::collections::{btree_map::Entry, BTreeMap, HashMap};
+
+use serde_json::Value;
+use tokio::time::timeout;
+
+use crate::{
+    cell::Ref, events::OnApplicationBootstrap, implements, injectable,
+    instance_wrapper::InstanceToken, DiscoveryService, ProviderInfo,
+};
+
+use super::indicator::{HealthIndicatorResult, HealthStatus, IHealthIndicator};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub indicators: BTreeMap<String, HealthIndicatorResult>,
+}
+
+impl HealthReport {
+    pub fn is_up(&self) -> bool {
+        self.status == HealthStatus::Up
+    }
+
+    pub fn get_http_status_code(&self) -> u16 {
+        self.status.get_http_status_code()
+    }
+
+    pub fn to_json(&self) -> Value {
+        let indicators = self
+            .indicators
+            .iter()
+            .map(|(name, result)| (name.clone(), result.to_json()))
+            .collect();
+
+        serde_json::json!({
+            "status": self.status.to_string(),
+            "indicators": Value::Object(indicators),
+        })
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+pub struct HealthService {
+    #[inject]
+    discovery: Ref<DiscoveryService>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl HealthService {
+    /// Returns the indicators of the container, the transient indicators get a new instance
+    pub async fn get_indicators(&self) -> Result<Vec<Ref<dyn IHealthIndicator>>, String> {
+        let mut indicators: Vec<Ref<dyn IHealthIndicator>> = Vec::new();
+
+        for provider in self.get_indicator_providers() {
+            indicators.push(self.get_indicator(&provider).await?);
+        }
+
+        Ok(indicators)
+    }
+
+    /// Runs the checks of all indicators concurrently.
+    ///
+    /// The app is down if any indicator is down, failed or timed out, and degraded if any indicator is degraded.
+    /// The indicators failed to resolve are reported as down by their provider token.
+    pub async fn check(&self) -> HealthReport {
+        let mut handles = Vec::new();
+        let mut indicators: BTreeMap<String, HealthIndicatorResult> = BTreeMap::new();
+
+        for provider in self.get_indicator_providers() {
+            let indicator = match self.get_indicator(&provider).await {
+                Ok(indicator) => indicator,
+                Err(err) => {
+                    indicators.insert(
+                        provider.get_token(),
+                        HealthIndicatorResult::down().with_detail("error", err),
+                    );
+                    continue;
+                }
+            };
+
+            let name = indicator.get_name();
+            let duration = indicator.get_timeout();
+
+            let handle =
+                tokio::spawn(async move { timeout(duration, indicator.as_ref().check()).await });
+
+            handles.push((name, duration, handle));
+        }
+
+        for (name, duration, handle) in handles {
+            let result = match handle.await {
+                Ok(Ok(result)) => result,
+                Ok(Err(_)) => HealthIndicatorResult::down().with_detail(
+                    "error",
+                    format!("timed out after {}ms", duration.as_millis()),
+                ),
+                Err(err) => HealthIndicatorResult::down().with_detail("error", err.to_string()),
+            };
+
+            // the names are checked on bootstrap, the results of the same name are never merged
+            match indicators.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(result);
+                }
+                Entry::Occupied(mut entry) => {
+                    let error = format!("several indicators are named {}", entry.key());
+
+                    entry.insert(HealthIndicatorResult::down().with_detail("error", error));
+                }
+            }
+        }
+
+        let status = indicators
+            .values()
+            .map(|result| result.status)
+            .max()
+            .unwrap_or(HealthStatus::Up);
+
+        HealthReport { status, indicators }
+    }
+
+    fn get_indicator_providers(&self) -> Vec<ProviderInfo> {
+        self.discovery
+            .get_providers_implementing::<dyn IHealthIndicator>()
+    }
+
+    async fn get_indicator(
+        &self,
+        provider: &ProviderInfo,
+    ) -> Result<Ref<dyn IHealthIndicator>, String> {
+        let instance = match provider.get_instance() {
+            Some(instance) => instance,
+            None => self.discovery.resolve(provider).await?,
+        };
+
+        instance
+            .cast::<dyn IHealthIndicator>()
+            .map_err(|err| err.to_string())
+    }
+
+    /// Rejects the indicators with the same name
+    async fn check_indicator_names(&self) -> Result<(), String> {
+        let mut tokens: HashMap<String, InstanceToken> = HashMap::new();
+
+        for provider in self.get_indicator_providers() {
+            let name = self.get_indicator(&provider).await?.get_name();
+
+            if let Some(token) = tokens.insert(name.clone(), provider.get_token()) {
+                return Err(format!(
+                    "HealthService: the {} indicator name is used by the {} and {} providers",
+                    name,
+                    token,
+                    provider.get_token()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnApplicationBootstrap for HealthService {
+    async fn on_application_bootstrap(&self) {
+        if let Err(err) = self.check_indicator_names().await {
+            panic!("{}", err);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    thread::JoinHandle,
+};
+
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::{implements, reference::Ref};
+
+use super::{interface::ILogger, record::LogRecord};
+
+/// What `AsyncLogger` does with a new record when the channel is full
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum OverflowPolicy {
+    /// Drops the new record
+    #[default]
+    Drop,
+    /// Waits until the channel has space, the backpressure is applied through the channel.
+    ///
+    /// On the multi-thread tokio runtime the wait is wrapped by `block_in_place`,
+    /// so the other tasks are moved off the worker thread.
+    Block,
+    /// Drops the oldest queued record
+    DropOldest,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AsyncLoggerOptions {
+    /// Max number of the queued records
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for AsyncLoggerOptions {
+    fn default() -> Self {
+        AsyncLoggerOptions {
+            capacity: 1024,
+            overflow: OverflowPolicy::Drop,
+        }
+    }
+}
+
+enum Message {
+    Record(LogRecord),
+    /// Acknowledged once the records queued before it are written
+    Flush(Sender<()>),
+}
+
+/// Writes the records to the wrapped logger on the background thread.
+///
+/// The records are sent to the bounded channel drained by the background thread, so the
+/// caller is not blocked by the slow sinks. The pending records are written on `flush`,
+/// app shutdown and drop.
+pub struct AsyncLogger {
+    logger: Ref<dyn ILogger>,
+    options: AsyncLoggerOptions,
+    sender: Option<Sender<Message>>,
+    /// Used to drop the oldest records on overflow
+    receiver: Receiver<Message>,
+    dropped_count: AtomicU64,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AsyncLogger {
+    pub fn new(logger: Ref<dyn ILogger>, options: AsyncLoggerOptions) -> AsyncLogger {
+        let (sender, receiver) = crossbeam_channel::bounded(options.capacity.max(1));
+
+        let worker = {
+            let receiver = receiver.clone();
+            let logger = logger.clone();
+
+            std::thread::Builder::new()
+                .name(String::from("blackbox-async-logger"))
+                .spawn(move || drain(receiver, logger))
+                .expect("Failed to spawn the async logger thread")
+        };
+
+        AsyncLogger {
+            logger,
+            options,
+            sender: Some(sender),
+            receiver,
+            dropped_count: AtomicU64::new(0),
+            worker: Mutex::new(Some(worker)),
+        }
+    }
+
+    pub fn get_options(&self) -> AsyncLoggerOptions {
+        self.options
+    }
+
+    /// Returns the number of the records dropped on overflow
+    pub fn get_dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    /// Blocks until all queued records are written
+    pub fn flush(&self) {
+        let (ack_sender, ack_receiver) = crossbeam_channel::bounded(1);
+
+        self.send_blocking(Message::Flush(ack_sender));
+
+        let _ = ack_receiver.recv();
+    }
+
+    fn get_sender(&self) -> &Sender<Message> {
+        self.sender
+            .as_ref()
+            .expect("The async logger channel is closed")
+    }
+
+    /// Waits for the space in the channel
+    fn send_blocking(&self, message: Message) {
+        // the channel isn't disconnected while the logger holds its receiver
+        let send = || {
+            let _ = self.get_sender().send(message);
+        };
+
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(send)
+            }
+            _ => send(),
+        }
+    }
+
+    fn enqueue(&self, record: LogRecord) {
+        let mut message = Message::Record(record);
+
+        loop {
+            match self.get_sender().try_send(message) {
+                Ok(()) | Err(TrySendError::Disconnected(_)) => return,
+                Err(TrySendError::Full(rejected)) => match self.options.overflow {
+                    OverflowPolicy::Drop => {
+                        self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                        return;
+                    }
+                    OverflowPolicy::Block => {
+                        self.send_blocking(rejected);
+                        return;
+                    }
+                    OverflowPolicy::DropOldest => {
+                        match self.receiver.try_recv() {
+                            Ok(Message::Record(_)) => {
+                                self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                            }
+                            // the flush waits for the records queued after it as well
+                            Ok(flush) => {
+                                self.send_blocking(flush);
+                            }
+                            Err(_) => {}
+                        }
+
+                        message = rejected;
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn drain(receiver: Receiver<Message>, logger: Ref<dyn ILogger>) {
+    // the channel is disconnected when the async logger is dropped
+    for message in receiver.iter() {
+        match message {
+            Message::Record(record) => logger.log_record(&record),
+            Message::Flush(ack) => {
+                let _ = ack.send(());
+            }
+        }
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for AsyncLogger {
+    fn log_record(&self, record: &LogRecord) {
+        self.enqueue(record.clone());
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        self.logger.set_context(ctx);
+    }
+    fn get_context(&self) -> String {
+        self.logger.get_context()
+    }
+    fn shutdown(&self) {
+        self.flush();
+        self.logger.shutdown();
+    }
+}
+
+impl Drop for AsyncLogger {
+    fn drop(&mut self) {
+        // the background thread writes the pending records and stops
+        self.sender.take();
+
+        if let Some(worker) = self.worker.lock().unwrap().take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -5,30 +5,22 @@ use crate::{
     ILogger,
 };
 
-use super::{console_logger::ConsoleLogger, level::LogLevel};
+use super::{
+    console_logger::{ConsoleLogger, DEFAULT_APP_NAME},
+    record::LogRecord,
+    sink::LogSink,
+};
 
-#[derive(Clone)]
-pub struct LogBufferRecord {
-    pub level: LogLevel,
-    pub msg: String,
-    pub ctx: String,
-}
-
-impl LogBufferRecord {
-    pub fn new<'a>(level: LogLevel, msg: &'a str, ctx: &'a str) -> LogBufferRecord {
-        LogBufferRecord {
-            level,
-            msg: msg.to_owned(),
-            ctx: ctx.to_owned(),
-        }
-    }
-}
+pub const DEFAULT_LOG_BUFFER_CAPACITY: usize = 1024;
 
 #[injectable]
 #[blackbox_di(crate)]
 pub struct LoggerBuffer {
-    instance: RefMut<Ref<dyn ILogger>>,
-    log_buffer: RefMut<Vec<LogBufferRecord>>,
+    primary_sink: RefMut<Option<LogSink>>,
+    sinks: RefMut<Vec<LogSink>>,
+    log_buffer: RefMut<Vec<LogRecord>>,
+    capacity: RefMut<usize>,
+    dropped_count: RefMut<usize>,
     is_buffer_attached: RefMut<bool>,
 }
 
@@ -37,19 +29,33 @@ pub struct LoggerBuffer {
 impl LoggerBuffer {
     #[factory]
     pub fn new() -> LoggerBuffer {
-        let instance = Ref::new(ConsoleLogger::new("App".to_string()))
+        let instance = Ref::new(ConsoleLogger::new(DEFAULT_APP_NAME.to_string()))
             .cast::<dyn ILogger>()
             .unwrap();
 
         LoggerBuffer {
             log_buffer: RefMut::new(Vec::new()),
-            instance: RefMut::new(instance),
+            capacity: RefMut::new(DEFAULT_LOG_BUFFER_CAPACITY),
+            dropped_count: RefMut::new(0),
+            primary_sink: RefMut::new(Some(LogSink::new(instance))),
+            sinks: RefMut::new(Vec::new()),
             is_buffer_attached: RefMut::new(false),
         }
     }
 
+    /// Replaces the primary sink, the console logger by default, the sinks added by `add_sink` are kept
     pub fn register_logger(&self, instance: Ref<dyn ILogger>) {
-        *self.instance.as_mut() = instance;
+        *self.primary_sink.as_mut() = Some(LogSink::new(instance));
+    }
+
+    /// Removes all sinks, the records are discarded
+    pub fn clear_sinks(&self) {
+        *self.primary_sink.as_mut() = None;
+        self.sinks.as_mut().clear();
+    }
+
+    pub fn add_sink(&self, sink: LogSink) {
+        self.sinks.as_mut().push(sink);
     }
 
     pub fn attach_buffer(&self) {
@@ -64,21 +70,60 @@ impl LoggerBuffer {
         self.is_buffer_attached.as_ref().clone()
     }
 
-    pub fn get_instance(&self) -> Ref<dyn ILogger> {
-        self.instance.as_ref().clone()
+    /// Returns the primary sink followed by the added ones
+    pub fn get_sinks(&self) -> Vec<LogSink> {
+        self.primary_sink
+            .as_ref()
+            .iter()
+            .chain(self.sinks.as_ref().iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn write_sinks(&self, record: &LogRecord) {
+        // the sinks can log themselves, so the lock is not held while writing
+        for sink in self.get_sinks() {
+            sink.write(record);
+        }
     }
 
-    pub fn get_logs(&self) -> Vec<LogBufferRecord> {
+    pub fn shutdown_sinks(&self) {
+        for sink in self.get_sinks() {
+            sink.get_logger().shutdown();
+        }
+    }
+
+    pub fn get_logs(&self) -> Vec<LogRecord> {
         self.log_buffer.as_ref().clone()
     }
 
-    pub fn write_log<'a>(&self, level: LogLevel, msg: &'a str, ctx: &'a str) {
-        self.log_buffer
-            .as_mut()
-            .push(LogBufferRecord::new(level.clone(), msg, ctx));
+    pub fn get_capacity(&self) -> usize {
+        *self.capacity.as_ref()
+    }
+
+    /// Sets the max number of the buffered records, the records above it are dropped
+    pub fn set_capacity(&self, capacity: usize) {
+        *self.capacity.as_mut() = capacity;
+    }
+
+    /// Returns the number of the records dropped since the last `clear_logs`
+    pub fn get_dropped_count(&self) -> usize {
+        *self.dropped_count.as_ref()
+    }
+
+    pub fn write_log(&self, record: LogRecord) {
+        let mut log_buffer = self.log_buffer.as_mut();
+
+        if log_buffer.len() >= self.get_capacity() {
+            *self.dropped_count.as_mut() += 1;
+            return;
+        }
+
+        log_buffer.push(record);
     }
 
     pub fn clear_logs(&self) {
         self.log_buffer.as_mut().clear();
+        *self.dropped_count.as_mut() = 0;
     }
 }
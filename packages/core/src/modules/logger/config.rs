@@ -0,0 +1,62 @@
+use crate::{cell::RefMut, implements, injectable};
+
+use super::{filter::LogFilter, level::LogLevel};
+
+/// Runtime configuration of the `Logger`, shared by all its instances
+#[injectable]
+#[blackbox_di(crate)]
+pub struct LoggerConfig {
+    filter: RefMut<LogFilter>,
+    env_error: Option<String>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl LoggerConfig {
+    #[factory]
+    pub fn new() -> LoggerConfig {
+        LoggerConfig::from_env_filter(LogFilter::from_env())
+    }
+
+    /// The invalid env filter is replaced by the default one, the error is logged on build
+    pub(crate) fn from_env_filter(env_filter: Result<LogFilter, String>) -> LoggerConfig {
+        let (filter, env_error) = match env_filter {
+            Ok(filter) => (filter, None),
+            Err(err) => (LogFilter::default(), Some(err)),
+        };
+
+        LoggerConfig {
+            filter: RefMut::new(filter),
+            env_error,
+        }
+    }
+
+    /// Returns the error of the `BLACKBOX_LOG` env variable
+    pub fn get_env_error(&self) -> Option<String> {
+        self.env_error.clone()
+    }
+
+    pub fn get_filter(&self) -> LogFilter {
+        self.filter.as_ref().clone()
+    }
+
+    pub fn set_filter(&self, filter: LogFilter) {
+        *self.filter.as_mut() = filter;
+    }
+
+    pub fn set_level(&self, level: LogLevel) {
+        self.filter.as_mut().set_level(level);
+    }
+
+    pub fn set_context_level(&self, ctx: &str, level: LogLevel) {
+        self.filter.as_mut().set_context_level(ctx, level);
+    }
+
+    pub fn remove_context_level(&self, ctx: &str) {
+        self.filter.as_mut().remove_context_level(ctx);
+    }
+
+    pub fn is_enabled(&self, level: LogLevel, ctx: &str) -> bool {
+        self.filter.as_ref().is_enabled(level, ctx)
+    }
+}
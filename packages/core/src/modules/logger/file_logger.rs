@@ -0,0 +1,245 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use crate::{cell::RefMut, implements, reference::Ref};
+
+use super::{
+    console_logger::{ConsoleLogger, DEFAULT_APP_NAME, DEFAULT_LOGGER_CONTEXT},
+    format::LogFormat,
+    interface::ILogger,
+    record::LogRecord,
+};
+
+/// Time-based rotation of the `FileLogger`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum LogRotation {
+    #[default]
+    Never,
+    Hourly,
+    Daily,
+}
+
+impl LogRotation {
+    fn get_period(&self, timestamp: &chrono::DateTime<chrono::Local>) -> Option<String> {
+        match self {
+            LogRotation::Never => None,
+            LogRotation::Hourly => Some(timestamp.format("%Y-%m-%d %H").to_string()),
+            LogRotation::Daily => Some(timestamp.format("%Y-%m-%d").to_string()),
+        }
+    }
+}
+
+/// Options of the `FileLogger`.
+///
+/// The rotated files are named `<path>.1`, `<path>.2`, ..., the first one is the newest.
+#[derive(Debug, Clone)]
+pub struct FileLoggerOptions {
+    pub path: PathBuf,
+    pub app_name: String,
+    pub format: LogFormat,
+    /// Rotates the file before it exceeds the size in bytes
+    pub max_size: Option<u64>,
+    pub rotation: LogRotation,
+    /// Number of the rotated files to keep
+    pub max_files: usize,
+}
+
+impl FileLoggerOptions {
+    pub fn new<P: Into<PathBuf>>(path: P) -> FileLoggerOptions {
+        FileLoggerOptions {
+            path: path.into(),
+            app_name: String::from(DEFAULT_APP_NAME),
+            format: LogFormat::Logfmt,
+            max_size: None,
+            rotation: LogRotation::Never,
+            max_files: 5,
+        }
+    }
+}
+
+struct FileState {
+    file: File,
+    size: u64,
+    period: Option<String>,
+}
+
+const FILE_LOGGER_CONTEXT: &str = "FileLogger";
+
+/// Writes the records to the file with size- and time-based rotation.
+///
+/// The write and rotation errors are logged by the error logger with the `FileLogger` context,
+/// the console logger by default.
+pub struct FileLogger {
+    options: FileLoggerOptions,
+    context: RefMut<String>,
+    state: Mutex<FileState>,
+    error_logger: Ref<dyn ILogger>,
+}
+
+impl FileLogger {
+    pub fn new(options: FileLoggerOptions) -> Result<FileLogger, String> {
+        if let Some(dir) = options.path.parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir).map_err(|err| {
+                    format!("FileLogger: failed to create {}: {}", dir.display(), err)
+                })?;
+            }
+        }
+
+        let file = FileLogger::open(&options, false)?;
+        let metadata = file
+            .metadata()
+            .map_err(|err| format!("FileLogger: {}: {}", options.path.display(), err))?;
+
+        // the period of the existing file is defined by its last modification
+        let modified_at = metadata
+            .modified()
+            .map(chrono::DateTime::<chrono::Local>::from)
+            .unwrap_or_else(|_| chrono::offset::Local::now());
+
+        let state = FileState {
+            file,
+            size: metadata.len(),
+            period: options.rotation.get_period(&modified_at),
+        };
+
+        let error_logger = Ref::new(ConsoleLogger::new(options.app_name.clone()))
+            .cast::<dyn ILogger>()
+            .unwrap();
+
+        Ok(FileLogger {
+            options,
+            context: RefMut::new(String::from(DEFAULT_LOGGER_CONTEXT)),
+            state: Mutex::new(state),
+            error_logger,
+        })
+    }
+
+    /// Replaces the logger of the write and rotation errors
+    pub fn with_error_logger(mut self, error_logger: Ref<dyn ILogger>) -> FileLogger {
+        self.error_logger = error_logger;
+        self
+    }
+
+    pub fn get_options(&self) -> &FileLoggerOptions {
+        &self.options
+    }
+
+    /// Returns the path of the rotated file, 1 is the newest
+    pub fn get_rotated_path(&self, idx: usize) -> PathBuf {
+        let mut path = self.options.path.clone().into_os_string();
+
+        path.push(format!(".{}", idx));
+
+        PathBuf::from(path)
+    }
+
+    fn open(options: &FileLoggerOptions, truncate: bool) -> Result<File, String> {
+        OpenOptions::new()
+            .create(true)
+            .append(!truncate)
+            .write(true)
+            .truncate(truncate)
+            .open(&options.path)
+            .map_err(|err| {
+                format!(
+                    "FileLogger: failed to open {}: {}",
+                    options.path.display(),
+                    err
+                )
+            })
+    }
+
+    fn rotate(&self, state: &mut FileState) -> Result<(), String> {
+        let max_files = self.options.max_files;
+
+        let rename = |from: PathBuf, to: PathBuf| -> Result<(), String> {
+            if from.exists() {
+                fs::rename(&from, &to).map_err(|err| {
+                    format!("FileLogger: failed to rotate {}: {}", from.display(), err)
+                })?;
+            }
+
+            Ok(())
+        };
+
+        if max_files > 0 {
+            let oldest = self.get_rotated_path(max_files);
+
+            if oldest.exists() {
+                fs::remove_file(&oldest).map_err(|err| {
+                    format!("FileLogger: failed to remove {}: {}", oldest.display(), err)
+                })?;
+            }
+
+            for idx in (1..max_files).rev() {
+                rename(self.get_rotated_path(idx), self.get_rotated_path(idx + 1))?;
+            }
+
+            rename(self.options.path.clone(), self.get_rotated_path(1))?;
+        }
+
+        state.file = FileLogger::open(&self.options, true)?;
+        state.size = 0;
+
+        Ok(())
+    }
+
+    fn write(&self, record: &LogRecord) -> Result<(), String> {
+        let line = format!(
+            "{}\n",
+            self.options
+                .format
+                .format_record(&self.options.app_name, record, 0, false)
+        );
+        let line_size = line.len() as u64;
+
+        let mut state = self.state.lock().unwrap();
+
+        let period = self.options.rotation.get_period(&record.timestamp);
+
+        if period.is_some() && state.period.is_some() && period > state.period {
+            self.rotate(&mut state)?;
+        }
+
+        if period > state.period {
+            state.period = period;
+        }
+
+        if let Some(max_size) = self.options.max_size {
+            if state.size > 0 && state.size + line_size > max_size {
+                self.rotate(&mut state)?;
+            }
+        }
+
+        state
+            .file
+            .write_all(line.as_bytes())
+            .map_err(|err| format!("FileLogger: failed to write: {}", err))?;
+
+        state.size += line_size;
+
+        Ok(())
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for FileLogger {
+    fn log_record(&self, record: &LogRecord) {
+        if let Err(err) = self.write(record) {
+            self.error_logger
+                .error_with_ctx(err.as_str(), FILE_LOGGER_CONTEXT);
+        }
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        *self.context.as_mut() = ctx.to_owned();
+    }
+    fn get_context(&self) -> String {
+        self.context.as_ref().clone()
+    }
+}
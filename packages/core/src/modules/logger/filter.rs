@@ -0,0 +1,93 @@
+use std::{collections::HashMap, str::FromStr};
+
+use super::level::LogLevel;
+
+pub(crate) const LOG_FILTER_ENV: &str = "BLACKBOX_LOG";
+
+/// Minimum log level with per-context overrides.
+///
+/// Parsed from directives like `info,ModuleLoader=warn,UserService=debug`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    level: LogLevel,
+    context_levels: HashMap<String, LogLevel>,
+}
+
+impl LogFilter {
+    pub fn new(level: LogLevel) -> LogFilter {
+        LogFilter {
+            level,
+            context_levels: HashMap::new(),
+        }
+    }
+
+    /// Reads the filter from the `BLACKBOX_LOG` env variable, all levels are enabled by default.
+    ///
+    /// Returns the error if the variable is invalid.
+    pub fn from_env() -> Result<LogFilter, String> {
+        let directives = match std::env::var(LOG_FILTER_ENV) {
+            Ok(directives) => directives,
+            Err(_) => return Ok(LogFilter::default()),
+        };
+
+        directives
+            .parse()
+            .map_err(|err| format!("{}={} is ignored: {}", LOG_FILTER_ENV, directives, err))
+    }
+
+    pub fn get_level(&self) -> LogLevel {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    pub fn get_context_level(&self, ctx: &str) -> LogLevel {
+        self.context_levels.get(ctx).copied().unwrap_or(self.level)
+    }
+
+    pub fn set_context_level(&mut self, ctx: &str, level: LogLevel) {
+        self.context_levels.insert(ctx.to_owned(), level);
+    }
+
+    pub fn remove_context_level(&mut self, ctx: &str) {
+        self.context_levels.remove(ctx);
+    }
+
+    pub fn with_context_level(mut self, ctx: &str, level: LogLevel) -> LogFilter {
+        self.set_context_level(ctx, level);
+        self
+    }
+
+    pub fn is_enabled(&self, level: LogLevel, ctx: &str) -> bool {
+        level.is_enabled_for(self.get_context_level(ctx))
+    }
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        LogFilter::new(LogLevel::DEBUG)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(directives: &str) -> Result<Self, Self::Err> {
+        let mut filter = LogFilter::default();
+
+        for directive in directives.split(',').map(str::trim) {
+            if directive.is_empty() {
+                continue;
+            }
+
+            match directive.split_once('=') {
+                Some((ctx, level)) => filter.set_context_level(ctx.trim(), level.parse()?),
+                None => filter.set_level(directive.parse()?),
+            }
+        }
+
+        Ok(filter)
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, str::FromStr};
+
+use colored::{ColoredString, Colorize};
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+
+use super::{level::LogLevel, record::LogRecord};
+
+pub(crate) const LOG_FORMAT_ENV: &str = "BLACKBOX_LOG_FORMAT";
+pub(crate) const NO_COLOR_ENV: &str = "NO_COLOR";
+
+type ColorFn = fn(text: String) -> ColoredString;
+
+static LEVEL_COLOR_TABLE: Lazy<HashMap<LogLevel, ColorFn>> = Lazy::new(|| {
+    let mut m: HashMap<LogLevel, ColorFn> = HashMap::new();
+    m.insert(LogLevel::EMERG, |text| text.bright_red());
+    m.insert(LogLevel::ALERT, |text| text.bright_magenta());
+    m.insert(LogLevel::CRIT, |text| text.magenta());
+    m.insert(LogLevel::ERROR, |text| text.red());
+    m.insert(LogLevel::WARN, |text| text.yellow());
+    m.insert(LogLevel::NOTICE, |text| text.green());
+    m.insert(LogLevel::INFO, |text| text.cyan());
+    m.insert(LogLevel::DEBUG, |text| text.white());
+    m
+});
+
+/// Output format of the `ConsoleLogger`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum LogFormat {
+    /// Colored human-readable lines
+    #[default]
+    Pretty,
+    /// One JSON object per line, the fields are placed next to the record keys
+    Json,
+    /// `key=value` pairs
+    Logfmt,
+}
+
+impl LogFormat {
+    /// Reads the format from the `BLACKBOX_LOG_FORMAT` env variable, `Pretty` by default
+    pub fn from_env() -> LogFormat {
+        std::env::var(LOG_FORMAT_ENV)
+            .ok()
+            .and_then(|format| format.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Formats the record, `elapsed_ms` is the time since the previous record used by the pretty format
+    pub fn format_record(
+        &self,
+        app_name: &str,
+        record: &LogRecord,
+        elapsed_ms: i64,
+        colored: bool,
+    ) -> String {
+        match self {
+            LogFormat::Pretty => format_pretty(app_name, record, elapsed_ms, colored),
+            LogFormat::Json => format_json(app_name, record),
+            LogFormat::Logfmt => format_logfmt(app_name, record),
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.trim().to_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            "logfmt" => Ok(LogFormat::Logfmt),
+            _ => Err(format!("LogFormat: unknown {} format.", format)),
+        }
+    }
+}
+
+/// Colors are disabled if stdout is not a terminal or the `NO_COLOR` env variable is set
+pub fn is_color_supported() -> bool {
+    use std::io::IsTerminal;
+
+    let no_color = std::env::var(NO_COLOR_ENV)
+        .map(|value| !value.is_empty())
+        .unwrap_or(false);
+
+    !no_color && std::io::stdout().is_terminal()
+}
+
+fn paint(text: String, color_fn: ColorFn, colored: bool) -> String {
+    if colored {
+        (color_fn)(text).to_string()
+    } else {
+        text
+    }
+}
+
+pub(crate) fn format_field_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn format_pretty(app_name: &str, record: &LogRecord, elapsed_ms: i64, colored: bool) -> String {
+    let color_fn = *LEVEL_COLOR_TABLE.get(&record.level).unwrap();
+
+    let fields = record
+        .fields
+        .iter()
+        .map(|(key, value)| format!(" {}={}", key, format_field_value(value)))
+        .collect::<String>();
+
+    format!(
+        "{:}    {:}    {:} {:} {:}{:} {:}",
+        paint(format!("[{:}]", app_name), |text| text.green(), colored),
+        record.timestamp.format("%m/%d/%Y, %H:%M:%S %p"),
+        format!(
+            "{:<8}",
+            paint(format!("[{}]", record.level), color_fn, colored)
+        ),
+        paint(format!("[{:}]", record.ctx), |text| text.yellow(), colored),
+        paint(record.msg.clone(), color_fn, colored),
+        paint(fields, |text| text.white(), colored),
+        paint(format!("+{:}ms", elapsed_ms), |text| text.yellow(), colored),
+    )
+}
+
+fn format_json(app_name: &str, record: &LogRecord) -> String {
+    let mut object = Map::new();
+
+    for (key, value) in &record.fields {
+        object.insert(key.clone(), value.clone());
+    }
+
+    // the record keys can't be overridden by the fields
+    object.insert(
+        String::from("timestamp"),
+        Value::from(record.timestamp.to_rfc3339()),
+    );
+    object.insert(String::from("level"), Value::from(record.level.to_string()));
+    object.insert(String::from("app"), Value::from(app_name));
+    object.insert(String::from("context"), Value::from(record.ctx.clone()));
+    object.insert(String::from("msg"), Value::from(record.msg.clone()));
+
+    Value::Object(object).to_string()
+}
+
+fn format_logfmt_value(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|char| char.is_whitespace() || char == '=' || char == '"');
+
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn format_logfmt(app_name: &str, record: &LogRecord) -> String {
+    let mut pairs = vec![
+        (String::from("time"), record.timestamp.to_rfc3339()),
+        (
+            String::from("level"),
+            record.level.to_string().to_lowercase(),
+        ),
+        (String::from("app"), app_name.to_string()),
+        (String::from("context"), record.ctx.clone()),
+        (String::from("msg"), record.msg.clone()),
+    ];
+
+    for (key, value) in &record.fields {
+        pairs.push((key.clone(), format_field_value(value)));
+    }
+
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, format_logfmt_value(value)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,14 +1,29 @@
 use crate::interface;
 
-use super::level::LogLevel;
+use super::{
+    level::LogLevel,
+    record::{LogField, LogRecord},
+};
 
 #[interface]
 #[blackbox_di(crate)]
 pub trait ILogger {
     fn set_context<'a>(&self, ctx: &'a str);
     fn get_context(&self) -> String;
-    fn log<'a>(&self, level: LogLevel, msg: &'a str);
-    fn log_with_ctx<'a>(&self, level: LogLevel, msg: &'a str, ctx: &'a str);
+    fn log_record(&self, record: &LogRecord);
+    /// Called on the app shutdown, buffered sinks write the pending records
+    fn shutdown(&self) {}
+    fn log<'a>(&self, level: LogLevel, msg: &'a str) {
+        self.log_with_ctx(level, msg, self.get_context().as_str());
+    }
+    fn log_with_ctx<'a>(&self, level: LogLevel, msg: &'a str, ctx: &'a str) {
+        self.log_record(&LogRecord::new(level, msg, ctx));
+    }
+    fn log_with_fields<'a>(&self, level: LogLevel, msg: &'a str, fields: Vec<LogField>) {
+        self.log_record(
+            &LogRecord::new(level, msg, self.get_context().as_str()).with_fields(fields),
+        );
+    }
     fn emerg<'a>(&self, msg: &'a str) {
         self.log(LogLevel::EMERG, msg);
     }
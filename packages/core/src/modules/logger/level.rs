@@ -24,3 +24,42 @@ impl std::fmt::Display for LogLevel {
         }
     }
 }
+
+impl LogLevel {
+    /// Returns the syslog severity, 0 is the most severe
+    pub fn get_severity(&self) -> u8 {
+        match self {
+            LogLevel::EMERG => 0,
+            LogLevel::ALERT => 1,
+            LogLevel::CRIT => 2,
+            LogLevel::ERROR => 3,
+            LogLevel::WARN => 4,
+            LogLevel::NOTICE => 5,
+            LogLevel::INFO => 6,
+            LogLevel::DEBUG => 7,
+        }
+    }
+
+    /// Checks if the level is at least as severe as the `min_level`
+    pub fn is_enabled_for(&self, min_level: LogLevel) -> bool {
+        self.get_severity() <= min_level.get_severity()
+    }
+}
+
+impl std::str::FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level.trim().to_lowercase().as_str() {
+            "emerg" => Ok(LogLevel::EMERG),
+            "alert" => Ok(LogLevel::ALERT),
+            "crit" => Ok(LogLevel::CRIT),
+            "error" => Ok(LogLevel::ERROR),
+            "warn" | "warning" => Ok(LogLevel::WARN),
+            "notice" => Ok(LogLevel::NOTICE),
+            "info" => Ok(LogLevel::INFO),
+            "debug" => Ok(LogLevel::DEBUG),
+            _ => Err(format!("LogLevel: unknown {} level.", level)),
+        }
+    }
+}
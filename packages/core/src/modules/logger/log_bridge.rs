@@ -0,0 +1,169 @@
+use std::cell::Cell;
+
+use log::kv::{Error as KvError, Key, Value as KvValue, VisitSource};
+use serde_json::Value;
+
+use crate::{cell::RefMut, implements, reference::Ref};
+
+use super::{
+    console_logger::DEFAULT_LOGGER_CONTEXT,
+    format::format_field_value,
+    interface::ILogger,
+    level::LogLevel,
+    logger::Logger,
+    record::{LogField, LogRecord},
+};
+
+fn to_log_level(level: LogLevel) -> log::Level {
+    match level {
+        LogLevel::EMERG | LogLevel::ALERT | LogLevel::CRIT | LogLevel::ERROR => log::Level::Error,
+        LogLevel::WARN => log::Level::Warn,
+        LogLevel::NOTICE | LogLevel::INFO => log::Level::Info,
+        LogLevel::DEBUG => log::Level::Debug,
+    }
+}
+
+fn from_log_level(level: log::Level) -> LogLevel {
+    match level {
+        log::Level::Error => LogLevel::ERROR,
+        log::Level::Warn => LogLevel::WARN,
+        log::Level::Info => LogLevel::INFO,
+        log::Level::Debug | log::Level::Trace => LogLevel::DEBUG,
+    }
+}
+
+thread_local! {
+    // set while `LogLogger` forwards a record, `LogBridge` drops such records to avoid loops
+    static FORWARDING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn is_forwarding() -> bool {
+    FORWARDING.with(|forwarding| forwarding.get())
+}
+
+/// Forwards the records to the `log` crate, the context becomes the target.
+///
+/// The forwarded records are skipped by `LogBridge`, so both can be used together.
+pub struct LogLogger {
+    context: RefMut<String>,
+}
+
+impl LogLogger {
+    pub fn new() -> LogLogger {
+        LogLogger {
+            context: RefMut::new(String::from(DEFAULT_LOGGER_CONTEXT)),
+        }
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for LogLogger {
+    fn log_record(&self, record: &LogRecord) {
+        let level = to_log_level(record.level);
+
+        if level > log::max_level() {
+            return;
+        }
+
+        let fields = record
+            .fields
+            .iter()
+            .map(|(key, value)| (key.clone(), format_field_value(value)))
+            .collect::<Vec<_>>();
+
+        let was_forwarding = FORWARDING.with(|forwarding| forwarding.replace(true));
+
+        log::logger().log(
+            &log::Record::builder()
+                .level(level)
+                .target(record.ctx.as_str())
+                .args(format_args!("{}", record.msg))
+                .key_values(&fields)
+                .build(),
+        );
+
+        FORWARDING.with(|forwarding| forwarding.set(was_forwarding));
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        *self.context.as_mut() = ctx.to_owned();
+    }
+    fn get_context(&self) -> String {
+        self.context.as_ref().clone()
+    }
+}
+
+struct FieldCollector {
+    fields: Vec<LogField>,
+}
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: KvValue<'kvs>) -> Result<(), KvError> {
+        let value = if let Some(value) = value.to_bool() {
+            Value::from(value)
+        } else if let Some(value) = value.to_i64() {
+            Value::from(value)
+        } else if let Some(value) = value.to_u64() {
+            Value::from(value)
+        } else if let Some(value) = value.to_f64() {
+            Value::from(value)
+        } else {
+            Value::from(value.to_string())
+        };
+
+        self.fields.push((key.to_string(), value));
+
+        Ok(())
+    }
+}
+
+/// Routes the records of the `log` crate to the `Logger`, the target becomes the context.
+///
+/// The records forwarded by `LogLogger` are skipped to avoid loops.
+pub struct LogBridge {
+    logger: Ref<Logger>,
+}
+
+impl LogBridge {
+    pub fn new(logger: Ref<Logger>) -> LogBridge {
+        LogBridge { logger }
+    }
+
+    /// Installs the bridge as the global logger of the `log` crate
+    pub fn install(self) -> Result<(), String> {
+        log::set_boxed_logger(Box::new(self)).map_err(|err| format!("LogBridge: {}", err))?;
+        log::set_max_level(log::LevelFilter::Trace);
+
+        Ok(())
+    }
+}
+
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        !is_forwarding()
+            && self
+                .logger
+                .is_enabled(from_log_level(metadata.level()), metadata.target())
+    }
+
+    fn log(&self, record: &log::Record) {
+        if is_forwarding() {
+            return;
+        }
+
+        let mut collector = FieldCollector { fields: Vec::new() };
+
+        let _ = record.key_values().visit(&mut collector);
+
+        self.logger.log_record(
+            &LogRecord::new(
+                from_log_level(record.level()),
+                record.args().to_string().as_str(),
+                record.target(),
+            )
+            .with_fields(collector.fields),
+        );
+    }
+
+    fn flush(&self) {}
+}
@@ -1,12 +1,14 @@
 use crate::{implements, injectable};
 
-use crate::{cell::RefMut, reference::Ref};
+use crate::{cell::RefMut, inquirer::Inquirer, reference::Ref, tokens::get_token};
 
 use super::{
-    buffer::LoggerBuffer, console_logger::DEFAULT_LOGGER_CONTEXT, interface::ILogger,
-    level::LogLevel,
+    buffer::LoggerBuffer, config::LoggerConfig, console_logger::DEFAULT_LOGGER_CONTEXT,
+    interface::ILogger, level::LogLevel, record::LogRecord, sink::LogSink,
 };
 
+const BUFFER_LOGGER_CONTEXT: &str = "LoggerBuffer";
+
 #[injectable]
 #[scope(Transient)]
 #[blackbox_di(crate)]
@@ -14,23 +16,59 @@ pub struct Logger {
     #[inject]
     buffer: Ref<LoggerBuffer>,
 
+    #[inject]
+    config: Ref<LoggerConfig>,
+
     context: RefMut<String>,
 }
 
 #[implements]
 #[blackbox_di(crate)]
 impl Logger {
+    /// The context is the name of the injecting provider, the logger resolved by the app has the default one
     #[factory]
-    pub fn new(buffer: Ref<LoggerBuffer>) -> Logger {
-        let context = RefMut::new(String::from(DEFAULT_LOGGER_CONTEXT));
+    pub fn new(
+        buffer: Ref<LoggerBuffer>,
+        config: Ref<LoggerConfig>,
+        #[inquirer] inquirer: Inquirer,
+    ) -> Logger {
+        let context = if inquirer.is_self(&get_token::<Logger>()) {
+            String::from(DEFAULT_LOGGER_CONTEXT)
+        } else {
+            inquirer.get_name()
+        };
+        let context = RefMut::new(context);
 
-        Logger { buffer, context }
+        Logger {
+            buffer,
+            config,
+            context,
+        }
     }
 
+    /// Replaces the primary sink, the sinks added by `add_sink` are kept
     pub fn register_logger(&self, instance: Ref<dyn ILogger>) {
         self.buffer.register_logger(instance);
     }
 
+    pub fn get_sinks(&self) -> Vec<LogSink> {
+        self.buffer.get_sinks()
+    }
+
+    /// Removes all sinks, the records are discarded
+    pub fn clear_sinks(&self) {
+        self.buffer.clear_sinks();
+    }
+
+    pub fn add_sink(&self, sink: LogSink) {
+        self.buffer.add_sink(sink);
+    }
+
+    /// Lets the sinks write their pending records
+    pub fn shutdown_sinks(&self) {
+        self.buffer.shutdown_sinks();
+    }
+
     pub fn attach_buffer(&self) {
         self.buffer.attach_buffer();
     }
@@ -39,11 +77,36 @@ impl Logger {
         self.buffer.detach_buffer();
     }
 
+    pub fn is_buffer_attached(&self) -> bool {
+        self.buffer.is_attached()
+    }
+
+    /// Sets the max number of the buffered records, the records above it are dropped and counted
+    pub fn set_buffer_capacity(&self, capacity: usize) {
+        self.buffer.set_capacity(capacity);
+    }
+
+    /// Checks the record against the filter of the `LoggerConfig`
+    pub fn is_enabled(&self, level: LogLevel, ctx: &str) -> bool {
+        self.config.is_enabled(level, ctx)
+    }
+
+    /// Replays the buffered records with their context and timestamp to the sinks
     pub fn flush(&self) {
         self.detach_buffer();
 
         for log_record in self.buffer.get_logs() {
-            self.log(log_record.level, log_record.msg.as_str())
+            self.log_record(&log_record)
+        }
+
+        let dropped_count = self.buffer.get_dropped_count();
+
+        if dropped_count > 0 {
+            let msg = format!("{} buffered log records were dropped", dropped_count);
+            let record = LogRecord::new(LogLevel::WARN, msg.as_str(), BUFFER_LOGGER_CONTEXT)
+                .with_field("dropped", dropped_count);
+
+            self.log_record(&record);
         }
 
         self.buffer.clear_logs();
@@ -53,25 +116,16 @@ impl Logger {
 #[implements]
 #[blackbox_di(crate)]
 impl ILogger for Logger {
-    fn log<'a>(&self, level: LogLevel, msg: &'a str) {
-        let ctx = self.context.as_ref();
-
-        if self.buffer.is_attached() {
-            return self.buffer.write_log(level, msg, ctx.as_str());
-        };
-
-        let logger_instance = self.buffer.get_instance();
+    fn log_record(&self, record: &LogRecord) {
+        if !self.config.is_enabled(record.level, record.ctx.as_str()) {
+            return;
+        }
 
-        logger_instance.log_with_ctx(level, msg, ctx.as_str());
-    }
-    fn log_with_ctx<'a>(&self, level: LogLevel, msg: &'a str, ctx: &'a str) {
         if self.buffer.is_attached() {
-            return self.buffer.write_log(level, msg, ctx);
+            return self.buffer.write_log(record.clone());
         };
 
-        let logger_instance = self.buffer.get_instance();
-
-        logger_instance.log_with_ctx(level, msg, ctx);
+        self.buffer.write_sinks(record);
     }
     fn set_context<'a>(&self, ctx: &'a str) {
         *self.context.as_mut() = ctx.to_owned();
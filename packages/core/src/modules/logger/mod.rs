@@ -1,16 +1,41 @@
+mod async_logger;
 mod buffer;
+mod config;
 mod console_logger;
+mod file_logger;
+mod filter;
+mod format;
 mod interface;
 mod level;
+#[cfg(feature = "log")]
+mod log_bridge;
 mod logger;
+mod record;
+mod sink;
+#[cfg(feature = "tracing")]
+mod tracing_bridge;
 
 use crate::module;
 
 use self::buffer::LoggerBuffer;
 
+pub use self::async_logger::{AsyncLogger, AsyncLoggerOptions, OverflowPolicy};
+pub use self::config::LoggerConfig;
+pub use self::console_logger::{ConsoleLogger, DEFAULT_APP_NAME};
+pub use self::file_logger::{FileLogger, FileLoggerOptions, LogRotation};
+pub use self::filter::LogFilter;
+pub use self::format::{is_color_supported, LogFormat};
 pub use self::interface::ILogger;
 pub use self::level::LogLevel;
+#[cfg(feature = "log")]
+pub use self::log_bridge::{LogBridge, LogLogger};
 pub use self::logger::Logger;
+pub use self::record::{LogField, LogRecord};
+pub use self::sink::LogSink;
+#[cfg(feature = "tracing")]
+pub use self::tracing_bridge::{
+    LoggerLayer, TracingLogger, MAX_TRACING_FIELDS, TRACING_EVENT_NAME,
+};
 
 #[module]
 #[blackbox_di(crate)]
@@ -18,6 +43,10 @@ pub struct LoggerModule {
     #[provider]
     pub buffer: LoggerBuffer,
 
+    #[provider]
+    #[export]
+    pub config: LoggerConfig,
+
     #[provider]
     #[export]
     pub logger: Logger,
@@ -0,0 +1,44 @@
+use serde_json::Value;
+
+use super::level::LogLevel;
+
+pub type LogField = (String, Value);
+
+/// A structured log record passed to `ILogger::log_record`
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    pub msg: String,
+    pub ctx: String,
+    pub fields: Vec<LogField>,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+}
+
+impl LogRecord {
+    pub fn new<'a>(level: LogLevel, msg: &'a str, ctx: &'a str) -> LogRecord {
+        LogRecord {
+            level,
+            msg: msg.to_owned(),
+            ctx: ctx.to_owned(),
+            fields: Vec::new(),
+            timestamp: chrono::offset::Local::now(),
+        }
+    }
+
+    pub fn with_field<'a>(mut self, key: &'a str, value: impl Into<Value>) -> LogRecord {
+        self.fields.push((key.to_owned(), value.into()));
+        self
+    }
+
+    pub fn with_fields(mut self, fields: Vec<LogField>) -> LogRecord {
+        self.fields.extend(fields);
+        self
+    }
+
+    pub fn get_field<'a>(&self, key: &'a str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(field_key, _)| field_key == key)
+            .map(|(_, value)| value)
+    }
+}
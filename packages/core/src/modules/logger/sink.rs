@@ -0,0 +1,46 @@
+use crate::reference::Ref;
+
+use super::{filter::LogFilter, interface::ILogger, level::LogLevel, record::LogRecord};
+
+/// A logger receiving the records of the `Logger` with its own level filter
+#[derive(Clone)]
+pub struct LogSink {
+    logger: Ref<dyn ILogger>,
+    filter: Option<LogFilter>,
+}
+
+impl LogSink {
+    pub fn new(logger: Ref<dyn ILogger>) -> LogSink {
+        LogSink {
+            logger,
+            filter: None,
+        }
+    }
+
+    /// Skips the records less severe than the `level`
+    pub fn with_level(self, level: LogLevel) -> LogSink {
+        self.with_filter(LogFilter::new(level))
+    }
+
+    pub fn with_filter(mut self, filter: LogFilter) -> LogSink {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn get_logger(&self) -> Ref<dyn ILogger> {
+        self.logger.clone()
+    }
+
+    pub fn is_enabled(&self, record: &LogRecord) -> bool {
+        match &self.filter {
+            Some(filter) => filter.is_enabled(record.level, record.ctx.as_str()),
+            None => true,
+        }
+    }
+
+    pub fn write(&self, record: &LogRecord) {
+        if self.is_enabled(record) {
+            self.logger.log_record(record);
+        }
+    }
+}
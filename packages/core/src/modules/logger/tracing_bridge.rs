@@ -0,0 +1,324 @@
+use std::{collections::HashMap, fmt::Debug, sync::RwLock};
+
+use once_cell::sync::{Lazy, OnceCell};
+use serde_json::Value;
+use tracing::{
+    field::{Field, FieldSet, Visit},
+    Event, Metadata, Subscriber,
+};
+use tracing_core::{
+    callsite::{self, Callsite},
+    identify_callsite,
+    metadata::Kind,
+    subscriber::Interest,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    Layer,
+};
+
+use crate::{cell::RefMut, implements, reference::Ref};
+
+use super::{
+    console_logger::DEFAULT_LOGGER_CONTEXT,
+    format::format_field_value,
+    interface::ILogger,
+    level::LogLevel,
+    logger::Logger,
+    record::{LogField, LogRecord},
+};
+
+/// The name of the events emitted by `TracingLogger`
+pub const TRACING_EVENT_NAME: &str = "blackbox_di";
+
+/// The max number of the record fields forwarded to `tracing`, the rest are skipped
+pub const MAX_TRACING_FIELDS: usize = 32;
+
+fn from_tracing_level(level: &tracing::Level) -> LogLevel {
+    match *level {
+        tracing::Level::ERROR => LogLevel::ERROR,
+        tracing::Level::WARN => LogLevel::WARN,
+        tracing::Level::INFO => LogLevel::INFO,
+        _ => LogLevel::DEBUG,
+    }
+}
+
+fn to_tracing_level(level: &LogLevel) -> tracing::Level {
+    match level {
+        LogLevel::EMERG | LogLevel::ALERT | LogLevel::CRIT | LogLevel::ERROR => {
+            tracing::Level::ERROR
+        }
+        LogLevel::WARN => tracing::Level::WARN,
+        LogLevel::NOTICE | LogLevel::INFO => tracing::Level::INFO,
+        LogLevel::DEBUG => tracing::Level::DEBUG,
+    }
+}
+
+/// The callsite of the events with the same context, level and field names
+struct RecordCallsite {
+    metadata: OnceCell<Metadata<'static>>,
+}
+
+impl Callsite for RecordCallsite {
+    fn set_interest(&self, _interest: Interest) {}
+
+    fn metadata(&self) -> &Metadata<'_> {
+        self.metadata
+            .get()
+            .expect("The callsite metadata is not set")
+    }
+}
+
+type CallsiteKey = (String, tracing::Level, Vec<String>);
+
+// the metadata of `tracing` is static, so the callsites are leaked once per key and reused
+static CALLSITES: Lazy<RwLock<HashMap<CallsiteKey, &'static RecordCallsite>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+fn leak_str(value: &str) -> &'static str {
+    Box::leak(value.to_owned().into_boxed_str())
+}
+
+fn create_callsite(key: &CallsiteKey) -> &'static RecordCallsite {
+    let (ctx, level, field_names) = key;
+    let callsite: &'static RecordCallsite = Box::leak(Box::new(RecordCallsite {
+        metadata: OnceCell::new(),
+    }));
+
+    let names = std::iter::once("message")
+        .chain(field_names.iter().map(|name| leak_str(name)))
+        .collect::<Vec<_>>();
+
+    let metadata = Metadata::new(
+        TRACING_EVENT_NAME,
+        leak_str(ctx),
+        *level,
+        None,
+        None,
+        None,
+        FieldSet::new(
+            Box::leak(names.into_boxed_slice()),
+            identify_callsite!(callsite),
+        ),
+        Kind::EVENT,
+    );
+
+    let _ = callsite.metadata.set(metadata);
+
+    callsite::register(callsite);
+
+    callsite
+}
+
+fn get_callsite(record: &LogRecord) -> &'static RecordCallsite {
+    let key: CallsiteKey = (
+        record.ctx.clone(),
+        to_tracing_level(&record.level),
+        record
+            .fields
+            .iter()
+            .take(MAX_TRACING_FIELDS)
+            .map(|(key, _value)| key.clone())
+            .collect(),
+    );
+
+    if let Some(callsite) = CALLSITES.read().unwrap().get(&key) {
+        return callsite;
+    }
+
+    return CALLSITES
+        .write()
+        .unwrap()
+        .entry(key)
+        .or_insert_with_key(create_callsite);
+}
+
+/// The value of the record field recorded by `tracing`
+enum FieldValue {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+}
+
+impl From<&Value> for FieldValue {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Bool(value) => FieldValue::Bool(*value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_u64() {
+                    FieldValue::U64(value)
+                } else if let Some(value) = number.as_i64() {
+                    FieldValue::I64(value)
+                } else {
+                    FieldValue::F64(number.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            value => FieldValue::Str(format_field_value(value)),
+        }
+    }
+}
+
+impl FieldValue {
+    fn as_value(&self) -> &dyn tracing::Value {
+        match self {
+            FieldValue::Bool(value) => value,
+            FieldValue::I64(value) => value,
+            FieldValue::U64(value) => value,
+            FieldValue::F64(value) => value,
+            FieldValue::Str(value) => value,
+        }
+    }
+}
+
+/// Forwards the records to `tracing` as events of the current span.
+///
+/// The context becomes the target and the record fields become the event fields.
+/// A callsite is registered once for every context, level and field names,
+/// up to `MAX_TRACING_FIELDS` fields are forwarded.
+pub struct TracingLogger {
+    context: RefMut<String>,
+}
+
+impl TracingLogger {
+    pub fn new() -> TracingLogger {
+        TracingLogger {
+            context: RefMut::new(String::from(DEFAULT_LOGGER_CONTEXT)),
+        }
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for TracingLogger {
+    fn log_record(&self, record: &LogRecord) {
+        let metadata = get_callsite(record).metadata();
+
+        if !tracing::dispatcher::get_default(|dispatch| dispatch.enabled(metadata)) {
+            return;
+        }
+
+        let message = format_args!("{}", record.msg);
+        let values = record
+            .fields
+            .iter()
+            .take(MAX_TRACING_FIELDS)
+            .map(|(_key, value)| FieldValue::from(value))
+            .collect::<Vec<_>>();
+        let fields = metadata.fields().iter().collect::<Vec<_>>();
+
+        // `ValueSet` is built from arrays only, the unused entries have no value
+        let field_values: [(&Field, Option<&dyn tracing::Value>); MAX_TRACING_FIELDS + 1] =
+            std::array::from_fn(|index| match index {
+                0 => (&fields[0], Some(&message as &dyn tracing::Value)),
+                index if index <= values.len() => {
+                    (&fields[index], Some(values[index - 1].as_value()))
+                }
+                _ => (&fields[0], None),
+            });
+
+        Event::dispatch(metadata, &metadata.fields().value_set(&field_values));
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        *self.context.as_mut() = ctx.to_owned();
+    }
+    fn get_context(&self) -> String {
+        self.context.as_ref().clone()
+    }
+}
+
+#[derive(Default)]
+struct FieldCollector {
+    msg: String,
+    fields: Vec<LogField>,
+}
+
+impl FieldCollector {
+    fn record_value(&mut self, field: &Field, value: Value) {
+        if field.name() == "message" {
+            self.msg = format_field_value(&value);
+        } else {
+            self.fields.push((field.name().to_string(), value));
+        }
+    }
+}
+
+impl Visit for FieldCollector {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record_value(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record_value(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record_value(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record_value(field, Value::from(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_value(field, Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.record_value(field, Value::from(format!("{:?}", value)));
+    }
+}
+
+/// Routes the `tracing` events to the `Logger`, the target becomes the context.
+///
+/// The events of `TracingLogger` are skipped to avoid loops.
+pub struct LoggerLayer {
+    logger: Ref<Logger>,
+}
+
+impl LoggerLayer {
+    pub fn new(logger: Ref<Logger>) -> LoggerLayer {
+        LoggerLayer { logger }
+    }
+
+    /// Installs the layer as the global `tracing` subscriber
+    pub fn install(self) -> Result<(), String> {
+        tracing::subscriber::set_global_default(tracing_subscriber::registry().with(self))
+            .map_err(|err| format!("LoggerLayer: {}", err))
+    }
+}
+
+impl<S: Subscriber> Layer<S> for LoggerLayer {
+    /// The `LoggerConfig` levels can change at runtime, so the interest is not cached
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if metadata.name() == TRACING_EVENT_NAME {
+            return Interest::never();
+        }
+
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        metadata.name() != TRACING_EVENT_NAME
+            && self
+                .logger
+                .is_enabled(from_tracing_level(metadata.level()), metadata.target())
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut collector = FieldCollector::default();
+
+        event.record(&mut collector);
+
+        self.logger.log_record(
+            &LogRecord::new(
+                from_tracing_level(metadata.level()),
+                collector.msg.as_str(),
+                metadata.target(),
+            )
+            .with_fields(collector.fields),
+        );
+    }
+}
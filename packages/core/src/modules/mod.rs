@@ -1,8 +1,16 @@
+#[cfg(feature = "config")]
+pub mod config;
+pub mod discovery;
+pub mod event_emitter;
+pub mod health;
 pub mod logger;
+pub mod module_ref;
+#[cfg(feature = "schedule")]
+pub mod schedule;
 
 use crate::module;
 
-use self::logger::LoggerModule;
+use self::{discovery::DiscoveryModule, logger::LoggerModule, module_ref::ModuleRefModule};
 
 #[module]
 #[global]
@@ -10,4 +18,10 @@ use self::logger::LoggerModule;
 pub struct CoreModule {
     #[import]
     pub logger_module: LoggerModule,
+
+    #[import]
+    pub discovery_module: DiscoveryModule,
+
+    #[import]
+    pub module_ref_module: ModuleRefModule,
 }
@@ -0,0 +1,13 @@
+mod module_ref;
+
+use crate::module;
+
+pub use self::module_ref::ModuleRef;
+
+#[module]
+#[blackbox_di(crate)]
+pub struct ModuleRefModule {
+    #[provider]
+    #[export]
+    pub module_ref: ModuleRef,
+}
@@ -0,0 +1,70 @@
+use blackbox_cast::CastFrom;
+
+use crate::{
+    cell::{Ref, RefMut},
+    implements, injectable,
+    inquirer::Inquirer,
+    instance_wrapper::InstanceToken,
+    module::Module,
+    module_context::ModuleContext,
+};
+
+/// Dynamic resolution of the providers from inside of the provider.
+///
+/// Every inquirer gets its own instance bound to the inquirer host module.
+/// The container is held weakly, the lookups fail after the app is dropped.
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+pub struct ModuleRef {
+    #[inquirer]
+    inquirer: Inquirer,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ModuleRef {
+    /// Returns the lookup bound to the host module, fails after the app is dropped
+    pub fn get_context(&self) -> Result<ModuleContext, String> {
+        self.inquirer
+            .get_module_context()
+            .map_err(|err| format!("ModuleRef: {}", err))
+    }
+
+    /// Returns the lookup limited by the providers of the host module
+    pub fn strict(&self) -> Result<ModuleContext, String> {
+        Ok(self.get_context()?.strict())
+    }
+
+    pub fn get_host(&self) -> Result<RefMut<Module>, String> {
+        Ok(self.get_context()?.get_module())
+    }
+
+    /// Retrieves an instance of either injectable, otherwise, throws error.
+    pub fn get<TInjectable: CastFrom>(&self) -> Result<Ref<TInjectable>, String> {
+        self.get_context()?.get::<TInjectable>()
+    }
+
+    /// Retrieves an instance of either injectable by token, otherwise, throws error.
+    pub fn get_by_token<TInjectable: ?Sized + CastFrom>(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<Ref<TInjectable>, String> {
+        self.get_context()?.get_by_token::<TInjectable>(token)
+    }
+
+    /// Creates a new instance of either transient injectable, otherwise, returns the shared instance.
+    pub async fn resolve<TInjectable: CastFrom>(&self) -> Result<Ref<TInjectable>, String> {
+        self.get_context()?.resolve::<TInjectable>().await
+    }
+
+    /// Creates a new instance of either transient injectable by token, otherwise, returns the shared instance.
+    pub async fn resolve_by_token<TInjectable: ?Sized + CastFrom>(
+        &self,
+        token: &InstanceToken,
+    ) -> Result<Ref<TInjectable>, String> {
+        self.get_context()?
+            .resolve_by_token::<TInjectable>(token)
+            .await
+    }
+}
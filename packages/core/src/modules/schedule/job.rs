@@ -0,0 +1,58 @@
+use std::{any::TypeId, fmt::Display, future::Future, pin::Pin};
+
+use blackbox_cast::linkme::distributed_slice;
+
+use crate::instance_wrapper::Instance;
+
+pub type JobHandlerFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+pub type JobHandlerFn = fn(instance: Instance) -> JobHandlerFuture;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobTrigger {
+    /// Runs the job every `ms` milliseconds
+    Interval(u64),
+    /// Runs the job once after `ms` milliseconds
+    Timeout(u64),
+    /// Runs the job by the cron expression with seconds, e.g. `0 */5 * * * *`
+    Cron(String),
+}
+
+impl Display for JobTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            JobTrigger::Interval(ms) => write!(f, "interval({}ms)", ms),
+            JobTrigger::Timeout(ms) => write!(f, "timeout({}ms)", ms),
+            JobTrigger::Cron(expression) => write!(f, "cron({})", expression),
+        }
+    }
+}
+
+/// Job of the provider method annotated with `#[interval]`, `#[timeout]` or `#[cron]`
+pub struct ScheduledJobRecord {
+    pub provider_id: TypeId,
+    pub method: &'static str,
+    pub name: Option<&'static str>,
+    pub trigger: JobTrigger,
+    pub handler: JobHandlerFn,
+}
+
+#[distributed_slice]
+#[linkme(crate = blackbox_cast::linkme)]
+pub static SCHEDULED_JOBS: [fn() -> ScheduledJobRecord] = [..];
+
+/// Conversion of the job result, jobs can return either `()` or `Result<(), E>`
+pub trait IntoJobResult {
+    fn into_job_result(self) -> Result<(), String>;
+}
+
+impl IntoJobResult for () {
+    fn into_job_result(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<E: Display> IntoJobResult for Result<(), E> {
+    fn into_job_result(self) -> Result<(), String> {
+        self.map_err(|err| err.to_string())
+    }
+}
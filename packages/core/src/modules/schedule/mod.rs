@@ -0,0 +1,17 @@
+mod job;
+mod registry;
+
+use crate::module;
+
+pub use self::job::{
+    IntoJobResult, JobHandlerFn, JobHandlerFuture, JobTrigger, ScheduledJobRecord, SCHEDULED_JOBS,
+};
+pub use self::registry::{JobInfo, SchedulerRegistry};
+
+#[module]
+#[blackbox_di(crate)]
+pub struct ScheduleModule {
+    #[provider]
+    #[export]
+    pub scheduler_registry: SchedulerRegistry,
+}
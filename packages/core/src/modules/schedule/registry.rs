@@ -0,0 +1,310 @@
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use tokio::{
+    task::JoinHandle,
+    time::{interval_at, sleep, Instant, MissedTickBehavior},
+};
+
+use crate::{
+    cell::{Ref, RefMut},
+    events::{OnApplicationBootstrap, OnModuleDestroy},
+    implements, injectable,
+    instance_wrapper::Instance,
+    DiscoveryService, ILogger, Logger,
+};
+
+use super::job::{JobHandlerFn, JobTrigger, SCHEDULED_JOBS};
+
+const SCHEDULER_CONTEXT: &str = "Scheduler";
+
+struct Job {
+    trigger: JobTrigger,
+    paused: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobInfo {
+    pub name: String,
+    pub trigger: JobTrigger,
+    pub paused: bool,
+    pub finished: bool,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+pub struct SchedulerRegistry {
+    #[inject]
+    discovery: Ref<DiscoveryService>,
+
+    #[inject]
+    logger: Ref<Logger>,
+
+    jobs: RefMut<HashMap<String, Job>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl SchedulerRegistry {
+    #[factory]
+    pub fn new(discovery: Ref<DiscoveryService>, logger: Ref<Logger>) -> SchedulerRegistry {
+        SchedulerRegistry {
+            discovery,
+            logger,
+            jobs: RefMut::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the jobs sorted by name
+    pub fn get_jobs(&self) -> Vec<JobInfo> {
+        let mut jobs = self
+            .jobs
+            .as_ref()
+            .iter()
+            .map(|(name, job)| get_job_info(name, job))
+            .collect::<Vec<_>>();
+
+        jobs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        jobs
+    }
+
+    pub fn get_job(&self, name: &str) -> Option<JobInfo> {
+        self.jobs
+            .as_ref()
+            .get(name)
+            .map(|job| get_job_info(name, job))
+    }
+
+    pub fn has_job(&self, name: &str) -> bool {
+        self.jobs.as_ref().contains_key(name)
+    }
+
+    /// Skips the runs of the job until it's resumed
+    pub fn pause(&self, name: &str) -> Result<(), String> {
+        self.set_paused(name, true)
+    }
+
+    pub fn resume(&self, name: &str) -> Result<(), String> {
+        self.set_paused(name, false)
+    }
+
+    /// Stops the job and removes it from the registry
+    pub fn cancel(&self, name: &str) -> Result<(), String> {
+        let job = self
+            .jobs
+            .as_mut()
+            .remove(name)
+            .ok_or_else(|| format!("SchedulerRegistry: {} job was not found.", name))?;
+
+        job.handle.abort();
+
+        Ok(())
+    }
+
+    /// Stops all jobs
+    pub fn stop_all(&self) {
+        for (_name, job) in self.jobs.as_mut().drain() {
+            job.handle.abort();
+        }
+    }
+
+    fn set_paused(&self, name: &str, paused: bool) -> Result<(), String> {
+        let jobs = self.jobs.as_ref();
+        let job = jobs
+            .get(name)
+            .ok_or_else(|| format!("SchedulerRegistry: {} job was not found.", name))?;
+
+        job.paused.store(paused, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    fn start_jobs(&self) {
+        for provider in self.discovery.get_providers() {
+            // transient providers have no shared instance
+            let instance = match provider.get_instance() {
+                Some(instance) => instance,
+                None => continue,
+            };
+
+            let provider_id = (*instance.as_ref()).type_id();
+
+            for record_fn in SCHEDULED_JOBS.iter() {
+                let record = (record_fn)();
+
+                if record.provider_id != provider_id {
+                    continue;
+                }
+
+                let name = match record.name {
+                    Some(name) => name.to_string(),
+                    None => format!("{}::{}", provider.get_token(), record.method),
+                };
+
+                self.start_job(name, record.trigger, instance.clone(), record.handler);
+            }
+        }
+    }
+
+    fn start_job(
+        &self,
+        name: String,
+        trigger: JobTrigger,
+        instance: Instance,
+        handler: JobHandlerFn,
+    ) {
+        if self.has_job(&name) {
+            panic!("SchedulerRegistry: {} job already exists", name);
+        }
+
+        let paused = Arc::new(AtomicBool::new(false));
+
+        let runner = JobRunner {
+            name: name.clone(),
+            instance,
+            handler,
+            paused: paused.clone(),
+            logger: self.logger.clone(),
+        };
+
+        let handle = match &trigger {
+            JobTrigger::Interval(ms) => {
+                let period = Duration::from_millis(*ms);
+
+                tokio::spawn(async move {
+                    let mut interval = interval_at(Instant::now() + period, period);
+
+                    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+                    loop {
+                        interval.tick().await;
+                        runner.run().await;
+                    }
+                })
+            }
+            JobTrigger::Timeout(ms) => {
+                let delay = Duration::from_millis(*ms);
+
+                tokio::spawn(async move {
+                    sleep(delay).await;
+                    runner.run().await;
+                })
+            }
+            JobTrigger::Cron(expression) => {
+                let schedule = Schedule::from_str(expression).unwrap_or_else(|err| {
+                    panic!(
+                        "SchedulerRegistry: {} job has invalid cron expression: {}",
+                        name, err
+                    )
+                });
+
+                let clock = SchedulerClock::new();
+
+                tokio::spawn(async move {
+                    // the next run is computed after the previous one, so the slow runs are not repeated
+                    while let Some(next) = schedule.after(&clock.now()).next() {
+                        let delay = (next - clock.now()).to_std().unwrap_or_default();
+
+                        sleep(delay).await;
+                        runner.run().await;
+                    }
+                })
+            }
+        };
+
+        self.jobs.as_mut().insert(
+            name,
+            Job {
+                trigger,
+                paused,
+                handle,
+            },
+        );
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnApplicationBootstrap for SchedulerRegistry {
+    async fn on_application_bootstrap(&self) {
+        self.start_jobs();
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnModuleDestroy for SchedulerRegistry {
+    async fn on_module_destroy(&self) {
+        self.stop_all();
+    }
+}
+
+struct JobRunner {
+    name: String,
+    instance: Instance,
+    handler: JobHandlerFn,
+    paused: Arc<AtomicBool>,
+    logger: Ref<Logger>,
+}
+
+impl JobRunner {
+    /// Runs the job in its own task, so failed runs don't stop the job
+    async fn run(&self) {
+        if self.paused.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let msg = match tokio::spawn((self.handler)(self.instance.clone())).await {
+            Ok(Ok(())) => return,
+            Ok(Err(msg)) => msg,
+            Err(err) => err.to_string(),
+        };
+
+        self.logger.error_with_ctx(
+            format!("The {} job failed: {}", self.name, msg).as_str(),
+            SCHEDULER_CONTEXT,
+        );
+    }
+}
+
+/// The wall clock advanced by the `tokio` clock, so the paused clock of the tests is respected
+struct SchedulerClock {
+    started_at: DateTime<Utc>,
+    started_instant: Instant,
+}
+
+impl SchedulerClock {
+    fn new() -> SchedulerClock {
+        SchedulerClock {
+            started_at: Utc::now(),
+            started_instant: Instant::now(),
+        }
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        let elapsed = chrono::Duration::from_std(self.started_instant.elapsed())
+            .unwrap_or_else(|_err| chrono::Duration::zero());
+
+        self.started_at + elapsed
+    }
+}
+
+fn get_job_info(name: &str, job: &Job) -> JobInfo {
+    JobInfo {
+        name: name.to_string(),
+        trigger: job.trigger.clone(),
+        paused: job.paused.load(Ordering::SeqCst),
+        finished: job.handle.is_finished(),
+    }
+}
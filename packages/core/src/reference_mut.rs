@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, Weak};
 
 pub struct RefMut<T: ?Sized> {
     value: Arc<RwLock<T>>,
@@ -35,3 +35,48 @@ impl<T: ?Sized> Clone for RefMut<T> {
         }
     }
 }
+
+impl<T: ?Sized> RefMut<T> {
+    pub fn downgrade(&self) -> WeakRefMut<T> {
+        WeakRefMut {
+            value: Arc::downgrade(&self.value),
+        }
+    }
+}
+
+/// Non-owning reference to the value of the `RefMut`, e.g. to the container held by its providers
+pub struct WeakRefMut<T: ?Sized> {
+    value: Weak<RwLock<T>>,
+}
+
+unsafe impl<T: ?Sized> Sync for WeakRefMut<T> {}
+unsafe impl<T: ?Sized> Send for WeakRefMut<T> {}
+
+impl<T: ?Sized> WeakRefMut<T> {
+    /// Returns the value if it's still alive
+    pub fn upgrade(&self) -> Option<RefMut<T>> {
+        self.value.upgrade().map(|value| RefMut { value })
+    }
+}
+
+impl<T: ?Sized> Clone for WeakRefMut<T> {
+    fn clone(&self) -> WeakRefMut<T> {
+        WeakRefMut {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> PartialEq for WeakRefMut<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl<T: ?Sized> std::fmt::Debug for WeakRefMut<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeakRefMut")
+            .field("is_alive", &(self.value.strong_count() > 0))
+            .finish()
+    }
+}
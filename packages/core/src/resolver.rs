@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use crate::{
+    app::call_init_hook_on_instances,
+    container::Container,
+    inquirer::Inquirer,
+    instance_wrapper::{InquirerId, Instance, InstanceWrapper, InstanceWrapperId, Scope},
+    interceptor::bind_interceptors,
+    reference_mut::RefMut,
+};
+
+/// Creates new instances of the providers at runtime.
+///
+/// Transient providers are instantiated with their own dependency subtree,
+/// singleton providers are taken from their host modules as is.
+pub struct Resolver {
+    container: RefMut<Container>,
+    resolved_instances: HashMap<(InstanceWrapperId, InquirerId), Instance>,
+    created_instances: Vec<Instance>,
+}
+
+impl Resolver {
+    pub fn new(container: RefMut<Container>) -> Resolver {
+        Resolver {
+            container,
+            resolved_instances: HashMap::new(),
+            created_instances: Vec::new(),
+        }
+    }
+
+    /// Resolves an instance of the provider.
+    ///
+    /// The instance of the transient provider is always a new one.
+    pub fn resolve(&mut self, wrapper: RefMut<InstanceWrapper>) -> Result<Instance, String> {
+        self.resolve_for_inquirer(wrapper.clone(), wrapper)
+    }
+
+    /// Returns the instances created by the resolver. Dependencies go before their inquirers.
+    pub fn get_created_instances(&self) -> Vec<Instance> {
+        self.created_instances.iter().rev().cloned().collect()
+    }
+
+    fn resolve_for_inquirer(
+        &mut self,
+        wrapper: RefMut<InstanceWrapper>,
+        inquirer: RefMut<InstanceWrapper>,
+    ) -> Result<Instance, String> {
+        let inquirer_id = inquirer.as_ref().get_id();
+
+        let (id, token, scope) = {
+            let wrapper = wrapper.as_ref();
+
+            (wrapper.get_id(), wrapper.get_token(), wrapper.get_scope())
+        };
+
+        match scope {
+            Scope::Singleton => wrapper
+                .as_ref()
+                .get_instance()
+                .ok_or_else(|| format!("The {} static instance was not found", &token)),
+            Scope::Transient => {
+                if let Some(instance) = self
+                    .resolved_instances
+                    .get(&(id.clone(), inquirer_id.clone()))
+                {
+                    return Ok(instance.clone());
+                }
+
+                let factory_fn = wrapper
+                    .as_ref()
+                    .get_factory_fn()
+                    .ok_or_else(|| format!("The {} provider has no factory", &token))?;
+
+                let instance = (factory_fn)(&Inquirer::from_wrapper(&inquirer, &self.container));
+
+                self.resolved_instances
+                    .insert((id.clone(), inquirer_id), instance.clone());
+                self.created_instances.push(instance.clone());
+
+                let host = wrapper.as_ref().get_host();
+                let dep_tokens = wrapper.as_ref().get_dep_tokens();
+
+                for dep_token in dep_tokens {
+                    let dep_wrapper = self
+                        .container
+                        .as_ref()
+                        .get_provider_in_module(&dep_token, host.clone())
+                        .ok_or_else(|| {
+                            format!(
+                                "The {} provider in the {} module was not found",
+                                &dep_token,
+                                &host.as_ref().get_token(),
+                            )
+                        })?;
+
+                    let dep_instance = self.resolve_for_inquirer(dep_wrapper, wrapper.clone())?;
+
+                    wrapper
+                        .as_ref()
+                        .link_dependency(&dep_token, instance.clone(), dep_instance)?;
+                }
+
+                bind_interceptors(self.container.clone(), &wrapper, &instance);
+
+                Ok(instance)
+            }
+            _ => Err(String::from("Unknown provider scope")),
+        }
+    }
+}
+
+/// Resolves an instance of the provider and triggers `on_module_init` of the created instances
+pub(crate) async fn resolve_instance(
+    container: RefMut<Container>,
+    wrapper: RefMut<InstanceWrapper>,
+) -> Result<Instance, String> {
+    let mut resolver = Resolver::new(container.clone());
+    let instance = resolver.resolve(wrapper)?;
+    let created_instances = resolver.get_created_instances();
+
+    call_init_hook_on_instances(created_instances).await;
+
+    Ok(instance)
+}
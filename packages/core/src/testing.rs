@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use crate::{cell::RefMut, implements, ILogger, LogLevel, LogRecord};
+
+const CAPTURING_LOGGER_CONTEXT: &str = "CapturingLogger";
+
+/// Matches the message of the captured record
+#[derive(Debug, Clone, PartialEq)]
+pub enum MsgMatcher {
+    Any,
+    Eq(String),
+    Contains(String),
+}
+
+impl MsgMatcher {
+    pub fn matches(&self, msg: &str) -> bool {
+        match self {
+            MsgMatcher::Any => true,
+            MsgMatcher::Eq(expected) => msg == expected,
+            MsgMatcher::Contains(pattern) => msg.contains(pattern.as_str()),
+        }
+    }
+}
+
+impl Display for MsgMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MsgMatcher::Any => write!(f, "any message"),
+            MsgMatcher::Eq(expected) => write!(f, "message equal to {:?}", expected),
+            MsgMatcher::Contains(pattern) => write!(f, "message containing {:?}", pattern),
+        }
+    }
+}
+
+pub fn any_msg() -> MsgMatcher {
+    MsgMatcher::Any
+}
+
+pub fn eq(msg: &str) -> MsgMatcher {
+    MsgMatcher::Eq(msg.to_string())
+}
+
+pub fn contains(pattern: &str) -> MsgMatcher {
+    MsgMatcher::Contains(pattern.to_string())
+}
+
+/// Logger recording every record for the assertions in tests.
+///
+/// Install it with `BuildParams::capture_logs` to capture the bootstrap logs too.
+pub struct CapturingLogger {
+    records: RefMut<Vec<LogRecord>>,
+    context: RefMut<String>,
+}
+
+impl CapturingLogger {
+    pub fn new() -> CapturingLogger {
+        CapturingLogger {
+            records: RefMut::new(Vec::new()),
+            context: RefMut::new(String::from(CAPTURING_LOGGER_CONTEXT)),
+        }
+    }
+
+    pub fn get_records(&self) -> Vec<LogRecord> {
+        self.records.as_ref().clone()
+    }
+
+    pub fn clear(&self) {
+        self.records.as_mut().clear();
+    }
+
+    /// Returns the records of the level matching the message
+    pub fn find(&self, level: LogLevel, msg: MsgMatcher) -> Vec<LogRecord> {
+        self.records
+            .as_ref()
+            .iter()
+            .filter(|record| record.level == level && msg.matches(&record.msg))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the records of the context
+    pub fn find_by_ctx(&self, ctx: &str) -> Vec<LogRecord> {
+        self.records
+            .as_ref()
+            .iter()
+            .filter(|record| record.ctx == ctx)
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_logged(&self, level: LogLevel, msg: MsgMatcher) -> bool {
+        !self.find(level, msg).is_empty()
+    }
+
+    /// Panics with the captured records if there is no record of the level matching the message
+    pub fn assert_logged(&self, level: LogLevel, msg: MsgMatcher) {
+        if !self.is_logged(level, msg.clone()) {
+            panic!(
+                "CapturingLogger: no {} record with {} was logged. Captured records:\n{}",
+                level,
+                msg,
+                self.describe_records()
+            );
+        }
+    }
+
+    /// Panics with the matching records if there is a record of the level matching the message
+    pub fn assert_not_logged(&self, level: LogLevel, msg: MsgMatcher) {
+        let records = self.find(level, msg.clone());
+
+        if !records.is_empty() {
+            panic!(
+                "CapturingLogger: {} {} records with {} were logged:\n{}",
+                records.len(),
+                level,
+                msg,
+                describe_records(&records)
+            );
+        }
+    }
+
+    fn describe_records(&self) -> String {
+        describe_records(&self.records.as_ref())
+    }
+}
+
+fn describe_records(records: &[LogRecord]) -> String {
+    if records.is_empty() {
+        return String::from("  (none)");
+    }
+
+    records
+        .iter()
+        .map(|record| format!("  [{}] [{}] {}", record.level, record.ctx, record.msg))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for CapturingLogger {
+    fn log_record(&self, record: &LogRecord) {
+        self.records.as_mut().push(record.clone());
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        *self.context.as_mut() = ctx.to_owned();
+    }
+    fn get_context(&self) -> String {
+        self.context.as_ref().clone()
+    }
+}
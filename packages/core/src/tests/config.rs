@@ -0,0 +1,189 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{
+    app::{build, BuildParams},
+    cell::Ref,
+    config, injectable, module,
+    modules::config::{ConfigModule, ConfigOptions, ConfigService},
+};
+
+#[config(prefix = "db", validate = "validate")]
+#[derive(Deserialize)]
+#[blackbox_di(crate)]
+struct DbConfig {
+    host: String,
+    port: u16,
+    pool: Option<u32>,
+}
+
+impl DbConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.port == 0 {
+            return Err(String::from("port must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repository {
+    #[inject]
+    db_config: Ref<DbConfig>,
+
+    #[inject]
+    config_service: Ref<ConfigService>,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    db_config: DbConfig,
+
+    #[provider]
+    repository: Repository,
+}
+
+fn write_file(name: &str, content: &str) -> String {
+    let path: PathBuf = env::temp_dir().join(format!("blackbox_config_{}", name));
+
+    fs::write(&path, content).unwrap();
+
+    path.to_string_lossy().to_string()
+}
+
+#[tokio::test]
+async fn config_merges_files_env_file_and_env_vars() {
+    let toml_file = write_file(
+        "merge.toml",
+        "name = \"app\"\n[db]\nhost = \"localhost\"\nport = 5432\npool = 4\n",
+    );
+    let json_file = write_file("merge.json", "{ \"db\": { \"host\": \"db.local\" } }");
+    let env_file = write_file(
+        "merge.env",
+        "MERGE_APP_DB__PORT=6543\nMERGE_APP_DEBUG=true\n",
+    );
+
+    env::set_var("MERGE_APP_DB__POOL", "8");
+
+    let app = build::<RootModule>(BuildParams::default().import(ConfigModule::for_root(
+        ConfigOptions {
+            env_file: Some(env_file),
+            files: vec![toml_file, json_file],
+            prefix: Some(String::from("MERGE_APP")),
+        },
+    )))
+    .await;
+
+    let repository = app.get::<Repository>().unwrap();
+
+    assert_eq!(repository.db_config.host, "db.local");
+    assert_eq!(repository.db_config.port, 6543);
+    assert_eq!(repository.db_config.pool, Some(8));
+
+    let config_service = app.get::<ConfigService>().unwrap();
+
+    assert_eq!(config_service.get::<u16>("db.port").unwrap(), 6543);
+    assert_eq!(config_service.get::<String>("name").unwrap(), "app");
+    assert!(config_service.get::<bool>("debug").unwrap());
+    assert!(config_service.get::<u16>("db.host").is_err());
+    assert!(!config_service.has("db.user"));
+    assert_eq!(
+        config_service.get_or("db.user", String::from("root")),
+        "root"
+    );
+}
+
+#[tokio::test]
+async fn config_env_vars_without_prefix_override_only_file_keys() {
+    let toml_file = write_file(
+        "no_prefix.toml",
+        "[db]\nhost = \"localhost\"\nport = 5432\n[no_prefix_cache]\nttl = 60\n",
+    );
+
+    env::set_var("NO_PREFIX_CACHE__TTL", "120");
+    env::set_var("NO_PREFIX_CACHE__SIZE", "10");
+    env::set_var("NO_PREFIX_CACHE", "disabled");
+
+    let app = build::<RootModule>(BuildParams::default().import(ConfigModule::for_root(
+        ConfigOptions {
+            files: vec![toml_file],
+            ..ConfigOptions::default()
+        },
+    )))
+    .await;
+
+    let config_service = app.get::<ConfigService>().unwrap();
+
+    assert_eq!(
+        config_service.get::<u32>("no_prefix_cache.ttl").unwrap(),
+        120
+    );
+    assert!(!config_service.has("no_prefix_cache.size"));
+    assert!(!config_service.has("path"));
+    assert!(!config_service.has("home"));
+}
+
+#[tokio::test]
+async fn config_env_file_without_prefix_adds_keys() {
+    let env_file = write_file(
+        "env_only.env",
+        "DB__HOST=env.local\nDB__PORT=7000\nENV_ONLY_CACHE__TTL=60\n",
+    );
+
+    env::set_var("ENV_ONLY_CACHE__TTL", "120");
+    env::set_var("ENV_ONLY_CACHE__SIZE", "10");
+
+    let app = build::<RootModule>(BuildParams::default().import(ConfigModule::for_root(
+        ConfigOptions {
+            env_file: Some(env_file),
+            files: vec![],
+            prefix: None,
+        },
+    )))
+    .await;
+
+    let repository = app.get::<Repository>().unwrap();
+
+    assert_eq!(repository.db_config.host, "env.local");
+    assert_eq!(repository.db_config.port, 7000);
+
+    let config_service = app.get::<ConfigService>().unwrap();
+
+    // the process variables override the keys of the env file, but don't add new ones
+    assert_eq!(
+        config_service.get::<u32>("env_only_cache.ttl").unwrap(),
+        120
+    );
+    assert!(!config_service.has("env_only_cache.size"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "port must be positive")]
+async fn config_validation_fails_build() {
+    let toml_file = write_file("invalid.toml", "[db]\nhost = \"localhost\"\nport = 0\n");
+
+    build::<RootModule>(
+        BuildParams::default().import(ConfigModule::for_root(ConfigOptions {
+            files: vec![toml_file],
+            ..ConfigOptions::default()
+        })),
+    )
+    .await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "missing field `host`")]
+async fn config_missing_field_fails_build() {
+    build::<RootModule>(
+        BuildParams::default().import(ConfigModule::for_root(ConfigOptions {
+            prefix: Some(String::from("MISSING_APP")),
+            ..ConfigOptions::default()
+        })),
+    )
+    .await;
+}
@@ -0,0 +1,141 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::Ref,
+    implements, injectable,
+    instance_wrapper::Scope,
+    interface, module,
+    tokens::get_token,
+    DiscoveryService,
+};
+
+#[interface]
+#[blackbox_di(crate)]
+trait IJobHandler {
+    fn queue(&self) -> String;
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct EmailHandler {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IJobHandler for EmailHandler {
+    fn queue(&self) -> String {
+        String::from("emails")
+    }
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct ReportHandler {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IJobHandler for ReportHandler {
+    fn queue(&self) -> String {
+        String::from("reports")
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repo {}
+
+#[module]
+#[blackbox_di(crate)]
+struct JobsModule {
+    #[provider]
+    #[export]
+    email_handler: EmailHandler,
+
+    #[provider]
+    report_handler: ReportHandler,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    jobs_module: JobsModule,
+
+    #[provider]
+    repo: Repo,
+}
+
+#[tokio::test]
+async fn discovers_providers_implementing_interface() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let discovery = app.get::<DiscoveryService>().unwrap();
+
+    let handlers = discovery.get_providers_implementing::<dyn IJobHandler>();
+
+    let mut tokens = handlers
+        .iter()
+        .map(|provider| provider.get_token())
+        .collect::<Vec<_>>();
+    tokens.sort();
+
+    assert_eq!(
+        tokens,
+        vec![get_token::<EmailHandler>(), get_token::<ReportHandler>()]
+    );
+
+    let email_handler = handlers
+        .iter()
+        .find(|provider| provider.get_token() == get_token::<EmailHandler>())
+        .unwrap();
+
+    assert_eq!(email_handler.get_host_token(), get_token::<JobsModule>());
+    assert_eq!(email_handler.get_scope(), Scope::Singleton);
+    assert_eq!(
+        email_handler
+            .get_instance_as::<dyn IJobHandler>()
+            .unwrap()
+            .queue(),
+        "emails"
+    );
+
+    let report_handler = handlers
+        .iter()
+        .find(|provider| provider.get_token() == get_token::<ReportHandler>())
+        .unwrap();
+
+    assert_eq!(report_handler.get_scope(), Scope::Transient);
+    assert!(report_handler.get_instance().is_none());
+}
+
+#[tokio::test]
+async fn discovers_providers_by_module() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let discovery = app.get::<DiscoveryService>().unwrap();
+
+    let providers = discovery.get_providers_by_module(&get_token::<RootModule>());
+
+    assert_eq!(providers.len(), 1);
+    assert_eq!(providers[0].get_token(), get_token::<Repo>());
+}
+
+#[test]
+fn discovery_fails_softly_after_app_is_dropped() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let (discovery, repo) = runtime.block_on(async {
+        let app = build::<RootModule>(BuildParams::default()).await;
+        let discovery = app.get::<DiscoveryService>().unwrap();
+        let repo = discovery.get_providers_by_module(&get_token::<RootModule>())[0].clone();
+
+        (discovery, repo)
+    });
+
+    // the runtime drops the app held by the exit signal listener
+    drop(runtime);
+
+    assert!(discovery.get_providers().is_empty());
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let error = runtime.block_on(discovery.resolve(&repo)).err().unwrap();
+
+    assert!(error.starts_with("DiscoveryService: "));
+}
@@ -0,0 +1,211 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    implements, injectable, interface, module,
+    modules::event_emitter::{is_topic_matched, Event, EventEmitter, EventEmitterModule},
+};
+
+struct UserCreated {
+    name: String,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Mailer {
+    sent: RefMut<Vec<String>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Mailer {
+    #[factory]
+    fn new() -> Mailer {
+        Mailer {
+            sent: RefMut::new(Vec::new()),
+        }
+    }
+
+    #[on_event("user.created")]
+    async fn send_welcome(&self, event: &UserCreated) {
+        self.sent.as_mut().push(format!("welcome {}", event.name));
+    }
+
+    #[on_event("user.*")]
+    fn audit(&self, event: &Event) -> Result<(), String> {
+        self.sent
+            .as_mut()
+            .push(format!("audit {}", event.get_topic()));
+        Ok(())
+    }
+
+    #[on_event("user.created")]
+    fn fail(&self) -> Result<(), String> {
+        Err(String::from("mail server is down"))
+    }
+
+    #[on_event("user.created")]
+    fn panic(&self) {
+        panic!("unexpected")
+    }
+}
+
+#[interface]
+#[blackbox_di(crate)]
+trait INotifier {
+    async fn notify(&self, event: &UserCreated);
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Notifier {
+    notified: RefMut<Vec<String>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Notifier {
+    #[factory]
+    fn new() -> Notifier {
+        Notifier {
+            notified: RefMut::new(Vec::new()),
+        }
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl INotifier for Notifier {
+    #[on_event("user.**")]
+    async fn notify(&self, event: &UserCreated) {
+        self.notified.as_mut().push(event.name.clone());
+    }
+}
+
+static ORDER_HANDLER_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+struct OrderPlaced {}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct OrderAudit {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OrderAudit {
+    #[on_event("order.placed")]
+    fn audit(&self, _event: &OrderPlaced) {
+        ORDER_HANDLER_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct OrderService {
+    #[inject]
+    audit: Ref<OrderAudit>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OrderService {
+    #[on_event("order.placed")]
+    fn on_created(&self, _event: &OrderPlaced) {
+        ORDER_HANDLER_CALLS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    event_emitter_module: EventEmitterModule,
+
+    #[provider]
+    mailer: Mailer,
+
+    #[provider]
+    notifier: Notifier,
+
+    #[provider]
+    order_audit: OrderAudit,
+
+    #[provider]
+    order_service: OrderService,
+}
+
+#[test]
+fn topic_wildcards() {
+    assert!(is_topic_matched("user.created", "user.created"));
+    assert!(!is_topic_matched("user.created", "user.deleted"));
+    assert!(is_topic_matched("user.*", "user.created"));
+    assert!(!is_topic_matched("user.*", "user.profile.updated"));
+    assert!(is_topic_matched("user.**", "user.profile.updated"));
+    assert!(is_topic_matched("**", "user.created"));
+    assert!(is_topic_matched("*.created", "user.created"));
+    assert!(!is_topic_matched("user.*", "user"));
+}
+
+#[tokio::test]
+async fn emit_async_calls_handlers_and_isolates_errors() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let event_emitter = app.get::<EventEmitter>().unwrap();
+
+    let errors = event_emitter
+        .emit_async(
+            "user.created",
+            UserCreated {
+                name: String::from("John"),
+            },
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .any(|error| error.msg == "mail server is down"));
+
+    let mut sent = app.get::<Mailer>().unwrap().sent.as_ref().clone();
+    sent.sort();
+
+    assert_eq!(sent, vec!["audit user.created", "welcome John"]);
+    assert_eq!(
+        *app.get::<Notifier>().unwrap().notified.as_ref(),
+        vec!["John"]
+    );
+}
+
+#[tokio::test]
+async fn handlers_skip_payloads_of_other_types() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let event_emitter = app.get::<EventEmitter>().unwrap();
+
+    assert!(event_emitter.emit_async("user.deleted", 1).await.is_ok());
+    assert!(!event_emitter.has_listeners("order.created"));
+
+    assert_eq!(
+        *app.get::<Mailer>().unwrap().sent.as_ref(),
+        vec!["audit user.deleted"]
+    );
+    assert!(app.get::<Notifier>().unwrap().notified.as_ref().is_empty());
+}
+
+#[tokio::test]
+async fn resolved_transient_instances_are_not_subscribed() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let event_emitter = app.get::<EventEmitter>().unwrap();
+
+    app.resolve::<OrderService>().await.unwrap();
+    app.resolve::<OrderService>().await.unwrap();
+
+    event_emitter.subscribe(app.get::<OrderAudit>().unwrap().cast().unwrap());
+
+    assert!(event_emitter
+        .emit_async("order.placed", OrderPlaced {})
+        .await
+        .is_ok());
+    assert_eq!(ORDER_HANDLER_CALLS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use crate::{
+    app::{build, BuildParams},
+    implements, injectable, module,
+    modules::health::{
+        HealthIndicatorResult, HealthModule, HealthService, HealthStatus, IHealthIndicator,
+    },
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct DatabaseIndicator {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for DatabaseIndicator {
+    fn get_name(&self) -> String {
+        String::from("database")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        HealthIndicatorResult::up().with_detail("connections", 5)
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct CacheIndicator {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for CacheIndicator {
+    fn get_name(&self) -> String {
+        String::from("cache")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        HealthIndicatorResult::degraded().with_detail("hit_rate", 0.1)
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct SlowIndicator {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for SlowIndicator {
+    fn get_name(&self) -> String {
+        String::from("slow")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        sleep(Duration::from_secs(10)).await;
+
+        HealthIndicatorResult::up()
+    }
+
+    fn get_timeout(&self) -> Duration {
+        Duration::from_millis(100)
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    health_module: HealthModule,
+
+    #[provider]
+    database: DatabaseIndicator,
+
+    #[provider]
+    cache: CacheIndicator,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct SlowModule {
+    #[import]
+    root_module: RootModule,
+
+    #[provider]
+    slow: SlowIndicator,
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct QueueIndicator {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for QueueIndicator {
+    fn get_name(&self) -> String {
+        String::from("queue")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        HealthIndicatorResult::up()
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct TransientModule {
+    #[import]
+    health_module: HealthModule,
+
+    #[provider]
+    queue: QueueIndicator,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct ReplicaIndicator {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IHealthIndicator for ReplicaIndicator {
+    fn get_name(&self) -> String {
+        String::from("database")
+    }
+
+    async fn check(&self) -> HealthIndicatorResult {
+        HealthIndicatorResult::down()
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct DuplicateModule {
+    #[import]
+    root_module: RootModule,
+
+    #[provider]
+    replica: ReplicaIndicator,
+}
+
+#[tokio::test]
+async fn health_service_aggregates_indicators() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let report = app.get::<HealthService>().unwrap().check().await;
+
+    assert_eq!(report.status, HealthStatus::Degraded);
+    assert_eq!(report.get_http_status_code(), 200);
+    assert_eq!(report.indicators["database"].status, HealthStatus::Up);
+    assert_eq!(report.indicators["lifecycle"].status, HealthStatus::Up);
+
+    let json = report.to_json();
+
+    assert_eq!(json["status"], "degraded");
+    assert_eq!(json["indicators"]["database"]["details"]["connections"], 5);
+    assert_eq!(
+        json["indicators"]["lifecycle"]["details"]["state"],
+        "running"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn health_service_reports_timed_out_indicator_as_down() {
+    let app = build::<SlowModule>(BuildParams::default()).await;
+    let report = app.get::<HealthService>().unwrap().check().await;
+
+    assert_eq!(report.status, HealthStatus::Down);
+    assert_eq!(report.get_http_status_code(), 503);
+    assert_eq!(
+        report.indicators["slow"].details["error"],
+        "timed out after 100ms"
+    );
+    assert_eq!(report.indicators["database"].status, HealthStatus::Up);
+}
+
+#[tokio::test]
+async fn lifecycle_indicator_is_down_after_close() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    app.close().await;
+
+    let report = app.get::<HealthService>().unwrap().check().await;
+
+    assert_eq!(report.indicators["lifecycle"].status, HealthStatus::Down);
+    assert_eq!(
+        report.indicators["lifecycle"].details["state"],
+        "shutting_down"
+    );
+}
+
+#[tokio::test]
+async fn health_service_reports_transient_indicators() {
+    let app = build::<TransientModule>(BuildParams::default()).await;
+    let health_service = app.get::<HealthService>().unwrap();
+
+    assert_eq!(health_service.get_indicators().await.unwrap().len(), 2);
+
+    let report = health_service.check().await;
+
+    assert_eq!(report.indicators["queue"].status, HealthStatus::Up);
+}
+
+#[tokio::test]
+#[should_panic(expected = "the database indicator name is used by")]
+async fn health_service_rejects_duplicate_indicator_names() {
+    build::<DuplicateModule>(BuildParams::default()).await;
+}
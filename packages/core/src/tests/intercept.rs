@@ -0,0 +1,241 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    implements, injectable,
+    interceptor::{AsyncNext, CallMetadata, CallResult, IInterceptor, SyncNext},
+    interface, module,
+    tokens::get_token,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct RecordingInterceptor {
+    calls: RefMut<Vec<String>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl RecordingInterceptor {
+    #[factory]
+    fn new() -> RecordingInterceptor {
+        RecordingInterceptor {
+            calls: RefMut::new(Vec::new()),
+        }
+    }
+
+    fn record(&self, call: &CallMetadata) {
+        self.calls.as_mut().push(format!(
+            "{}::{}",
+            call.interface.unwrap_or("-"),
+            call.method
+        ));
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IInterceptor for RecordingInterceptor {
+    fn intercept(&self, call: &CallMetadata, next: &SyncNext<'_>) -> CallResult {
+        self.record(call);
+        next()
+    }
+
+    async fn intercept_async(&self, call: &CallMetadata, next: &AsyncNext<'_>) -> CallResult {
+        self.record(call);
+        next().await
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct RetryInterceptor {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IInterceptor for RetryInterceptor {
+    fn intercept(&self, _call: &CallMetadata, next: &SyncNext<'_>) -> CallResult {
+        let result = next();
+
+        match result.downcast_ref::<Result<u32, String>>() {
+            Some(Err(_)) => next(),
+            _ => result,
+        }
+    }
+}
+
+#[interface]
+#[blackbox_di(crate)]
+trait IGreeter {
+    async fn greet(&self, name: String) -> String;
+
+    fn get_name(&self) -> String;
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Greeter {}
+
+#[implements]
+#[blackbox_di(crate)]
+#[intercept(RecordingInterceptor)]
+impl IGreeter for Greeter {
+    async fn greet(&self, name: String) -> String {
+        format!("Hello, {}!", name)
+    }
+
+    fn get_name(&self) -> String {
+        String::from("greeter")
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Flaky {
+    attempts: RefMut<u32>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Flaky {
+    #[factory]
+    fn new() -> Flaky {
+        Flaky {
+            attempts: RefMut::new(0),
+        }
+    }
+
+    #[intercept(RecordingInterceptor, RetryInterceptor)]
+    fn fetch(&self, fail_times: u32) -> Result<u32, String> {
+        *self.attempts.as_mut() += 1;
+
+        let attempts = *self.attempts.as_ref();
+
+        if attempts <= fail_times {
+            return Err(format!("attempt {} failed", attempts));
+        }
+
+        Ok(attempts)
+    }
+
+    fn get_attempts(&self) -> u32 {
+        *self.attempts.as_ref()
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Catalog {
+    title: String,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+#[intercept(RecordingInterceptor)]
+impl Catalog {
+    #[factory]
+    fn new() -> Catalog {
+        Catalog {
+            title: String::from("catalog"),
+        }
+    }
+
+    fn count(&self) -> usize {
+        3
+    }
+
+    // the methods below are not intercepted by the block interceptors
+    fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    fn append_title(&self, output: &mut String) {
+        output.push_str(&self.title);
+    }
+
+    fn describe(&self, item: impl ToString) -> String {
+        format!("{} of {}", item.to_string(), self.title)
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    recording: RecordingInterceptor,
+
+    #[provider]
+    retry: RetryInterceptor,
+
+    #[provider]
+    greeter: Greeter,
+
+    #[provider]
+    flaky: Flaky,
+
+    #[provider]
+    catalog: Catalog,
+}
+
+#[tokio::test]
+async fn intercept_calls_interceptors_of_impl_block() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let greeter = app
+        .get_by_token::<dyn IGreeter>(&get_token::<Greeter>())
+        .unwrap();
+    let recording = app.get::<RecordingInterceptor>().unwrap();
+
+    assert_eq!(greeter.greet(String::from("Bob")).await, "Hello, Bob!");
+    assert_eq!(greeter.get_name(), "greeter");
+
+    assert_eq!(
+        *recording.calls.as_ref(),
+        vec![
+            String::from("IGreeter::greet"),
+            String::from("IGreeter::get_name")
+        ]
+    );
+}
+
+#[tokio::test]
+async fn intercept_calls_next_several_times() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let flaky = app.get::<Flaky>().unwrap();
+    let recording = app.get::<RecordingInterceptor>().unwrap();
+
+    assert_eq!(flaky.fetch(1), Ok(2));
+    assert_eq!(flaky.get_attempts(), 2);
+
+    assert_eq!(flaky.fetch(5), Err(String::from("attempt 4 failed")));
+    assert_eq!(flaky.get_attempts(), 4);
+
+    // the outer interceptor is called once per call
+    assert_eq!(
+        *recording.calls.as_ref(),
+        vec![String::from("-::fetch"), String::from("-::fetch")]
+    );
+}
+
+#[test]
+fn intercept_is_skipped_for_unbound_instances() {
+    let flaky = Flaky::new();
+
+    assert_eq!(flaky.fetch(1), Err(String::from("attempt 1 failed")));
+}
+
+#[tokio::test]
+async fn block_interceptors_skip_not_interceptable_methods() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let catalog = app.get::<Catalog>().unwrap();
+    let recording = app.get::<RecordingInterceptor>().unwrap();
+
+    let mut output = String::new();
+
+    catalog.append_title(&mut output);
+
+    assert_eq!(catalog.count(), 3);
+    assert_eq!(catalog.get_title(), "catalog");
+    assert_eq!(output, "catalog");
+    assert_eq!(catalog.describe(1), "1 of catalog");
+
+    assert_eq!(*recording.calls.as_ref(), vec![String::from("-::count")]);
+}
@@ -0,0 +1,946 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    events::OnModuleInit,
+    implements, injectable,
+    inquirer::Inquirer,
+    module,
+    tokens::get_token,
+    AsyncLogger, AsyncLoggerOptions, ConsoleLogger, FileLogger, FileLoggerOptions, ILogger,
+    LogFilter, LogFormat, LogLevel, LogRecord, LogRotation, LogSink, Logger, LoggerConfig,
+    OverflowPolicy,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct RecordingLogger {
+    records: RefMut<Vec<LogRecord>>,
+    context: RefMut<String>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl RecordingLogger {
+    #[factory]
+    fn new() -> RecordingLogger {
+        RecordingLogger {
+            records: RefMut::new(Vec::new()),
+            context: RefMut::new(String::from("RecordingLogger")),
+        }
+    }
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for RecordingLogger {
+    fn log_record(&self, record: &LogRecord) {
+        self.records.as_mut().push(record.clone());
+    }
+    fn set_context<'a>(&self, ctx: &'a str) {
+        *self.context.as_mut() = ctx.to_owned();
+    }
+    fn get_context(&self) -> String {
+        self.context.as_ref().clone()
+    }
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct RequestScope {
+    #[inquirer]
+    inquirer: Inquirer,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct UserService {
+    #[inject]
+    logger: Ref<Logger>,
+
+    #[inject]
+    scope: Ref<RequestScope>,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    recording_logger: RecordingLogger,
+
+    #[provider]
+    request_scope: RequestScope,
+
+    #[provider]
+    user_service: UserService,
+}
+
+#[tokio::test]
+async fn logger_passes_structured_records() {
+    let app = build::<RootModule>(BuildParams::default().defer_logger()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.info_with_ctx("buffered", "ModuleLoader");
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    // the buffered records keep their context
+    assert!(recording_logger
+        .records
+        .as_ref()
+        .iter()
+        .any(|record| record.ctx == "ModuleLoader" && record.msg == "buffered"));
+
+    recording_logger.records.as_mut().clear();
+
+    logger.set_context("UserService");
+    logger.log_with_fields(
+        LogLevel::INFO,
+        "user created",
+        vec![
+            (String::from("user_id"), 42.into()),
+            (String::from("request_id"), "abc".into()),
+        ],
+    );
+    logger.warn("slow query");
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].level, LogLevel::INFO);
+    assert_eq!(records[0].msg, "user created");
+    assert_eq!(records[0].ctx, "UserService");
+    assert_eq!(records[0].get_field("user_id"), Some(&42.into()));
+    assert_eq!(records[0].get_field("request_id"), Some(&"abc".into()));
+
+    assert_eq!(records[1].level, LogLevel::WARN);
+    assert_eq!(records[1].ctx, "UserService");
+    assert!(records[1].fields.is_empty());
+}
+
+#[tokio::test]
+async fn logger_gets_context_of_injecting_provider() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let user_service = app.get::<UserService>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    user_service.logger.info("user created");
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].ctx, "UserService");
+
+    let inquirer = &user_service.scope.inquirer;
+
+    assert_eq!(inquirer.get_name(), "UserService");
+    assert_eq!(inquirer.get_token(), get_token::<UserService>());
+    assert_eq!(inquirer.get_module(), get_token::<RootModule>());
+
+    // the logger resolved by the app keeps the default context
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    assert_eq!(logger.get_context(), "ConsoleLogger");
+}
+
+#[tokio::test]
+async fn logger_counts_dropped_buffered_records() {
+    let params = BuildParams::default().defer_logger().log_buffer_capacity(2);
+    let app = build::<RootModule>(params).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    // the logger is deferred, so the logs wait for use_logger
+    assert!(logger.is_buffer_attached());
+
+    logger.info_with_ctx("first", "Bootstrap");
+    logger.info_with_ctx("second", "Bootstrap");
+    logger.info_with_ctx("third", "Bootstrap");
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    let records = recording_logger.records.as_ref().clone();
+    let messages = records
+        .iter()
+        .map(|record| record.msg.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(messages.contains(&"first"));
+    assert!(messages.contains(&"second"));
+    assert!(!messages.contains(&"third"));
+
+    let dropped = records.last().unwrap();
+
+    assert_eq!(dropped.level, LogLevel::WARN);
+    assert_eq!(dropped.ctx, "LoggerBuffer");
+    assert_eq!(dropped.msg, "1 buffered log records were dropped");
+    assert_eq!(dropped.get_field("dropped"), Some(&1.into()));
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct PlainService {}
+
+#[module]
+#[blackbox_di(crate)]
+struct PlainModule {
+    #[provider]
+    plain_service: PlainService,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct BootstrapService {
+    #[inject]
+    logger: Ref<Logger>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnModuleInit for BootstrapService {
+    async fn on_module_init(&self) {
+        self.logger.info("bootstrap finished");
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct BootstrapModule {
+    #[provider]
+    recording_logger: RecordingLogger,
+
+    #[provider]
+    bootstrap_service: BootstrapService,
+}
+
+#[tokio::test]
+async fn logger_flushes_buffer_without_deferred_logger() {
+    let recording_logger = Ref::new(RecordingLogger::new());
+    let params = BuildParams::default().buffer_logs().log_sink(LogSink::new(
+        recording_logger.clone().cast::<dyn ILogger>().unwrap(),
+    ));
+    // the app has a provider implementing ILogger, but the logger isn't deferred
+    let app = build::<BootstrapModule>(params).await;
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    assert!(!logger.is_buffer_attached());
+
+    // the bootstrap records are replayed with their context
+    assert!(recording_logger
+        .records
+        .as_ref()
+        .iter()
+        .any(|record| record.ctx == "BootstrapService" && record.msg == "bootstrap finished"));
+
+    logger.info("after build");
+
+    assert!(recording_logger
+        .records
+        .as_ref()
+        .iter()
+        .any(|record| record.msg == "after build"));
+}
+
+#[tokio::test]
+async fn build_params_set_logger() {
+    let recording_logger = Ref::new(RecordingLogger::new());
+    let params =
+        BuildParams::default().logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+    let app = build::<PlainModule>(params).await;
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.info("custom logger");
+
+    assert_eq!(logger.get_sinks().len(), 1);
+    assert_eq!(recording_logger.records.as_ref()[0].msg, "custom logger");
+}
+
+#[tokio::test]
+async fn build_params_set_logger_provider() {
+    let params = BuildParams::default().logger_provider::<RecordingLogger>();
+    let app = build::<RootModule>(params).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.info("logger provider");
+
+    assert!(recording_logger
+        .records
+        .as_ref()
+        .iter()
+        .any(|record| record.msg == "logger provider"));
+}
+
+#[tokio::test]
+async fn build_params_silence_console_logger() {
+    let recording_logger = Ref::new(RecordingLogger::new());
+    let params = BuildParams::default().silent().log_sink(LogSink::new(
+        recording_logger.clone().cast::<dyn ILogger>().unwrap(),
+    ));
+    let app = build::<PlainModule>(params).await;
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.info("only sinks");
+
+    assert_eq!(logger.get_sinks().len(), 1);
+    assert_eq!(recording_logger.records.as_ref()[0].msg, "only sinks");
+}
+
+#[tokio::test]
+async fn build_params_set_app_name() {
+    let app = build::<PlainModule>(BuildParams::default().app_name("Billing")).await;
+    let logger = app.resolve::<Logger>().await.unwrap();
+    let sinks = logger.get_sinks();
+    let console_logger = sinks[0].get_logger().cast::<ConsoleLogger>().unwrap();
+
+    assert_eq!(console_logger.get_app_name(), "Billing");
+}
+
+#[test]
+fn log_record_collects_fields() {
+    let record = LogRecord::new(LogLevel::DEBUG, "request finished", "Http")
+        .with_field("status", 200)
+        .with_field("duration_ms", 12.5);
+
+    assert_eq!(record.get_field("status"), Some(&200.into()));
+    assert_eq!(record.get_field("duration_ms"), Some(&12.5.into()));
+    assert_eq!(record.get_field("path"), None);
+}
+
+fn get_record() -> LogRecord {
+    LogRecord::new(LogLevel::WARN, "slow query", "UserService")
+        .with_field("duration_ms", 1200)
+        .with_field("query", "SELECT \"name\" FROM users")
+        .with_field("msg", "overridden")
+}
+
+#[test]
+fn log_format_formats_json_lines() {
+    let line = LogFormat::Json.format_record("App", &get_record(), 0, false);
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+
+    assert_eq!(value["level"], "WARN");
+    assert_eq!(value["app"], "App");
+    assert_eq!(value["context"], "UserService");
+    assert_eq!(value["msg"], "slow query");
+    assert_eq!(value["duration_ms"], 1200);
+    assert_eq!(value["query"], "SELECT \"name\" FROM users");
+    assert!(value["timestamp"].is_string());
+}
+
+#[test]
+fn log_format_formats_logfmt_lines() {
+    let line = LogFormat::Logfmt.format_record("App", &get_record(), 0, false);
+
+    assert!(line.starts_with("time="));
+    assert!(line.contains(
+        " level=warn app=App context=UserService msg=\"slow query\" duration_ms=1200 query=\"SELECT \\\"name\\\" FROM users\""
+    ));
+}
+
+#[test]
+fn log_format_formats_pretty_lines_without_colors() {
+    let line = LogFormat::Pretty.format_record("App", &get_record(), 5, false);
+
+    assert!(!line.contains('\u{1b}'));
+    assert!(line.starts_with("[App]"));
+    assert!(line.contains("[WARN]"));
+    assert!(line.contains("[UserService] slow query duration_ms=1200"));
+    assert!(line.ends_with("+5ms"));
+}
+
+#[test]
+fn log_format_is_parsed_from_string() {
+    assert_eq!("json".parse::<LogFormat>(), Ok(LogFormat::Json));
+    assert_eq!(" LOGFMT ".parse::<LogFormat>(), Ok(LogFormat::Logfmt));
+    assert_eq!("pretty".parse::<LogFormat>(), Ok(LogFormat::Pretty));
+    assert!("xml".parse::<LogFormat>().is_err());
+}
+
+#[test]
+fn log_filter_is_parsed_from_directives() {
+    let filter = "info, ModuleLoader=warn,UserService=debug"
+        .parse::<LogFilter>()
+        .unwrap();
+
+    assert_eq!(filter.get_level(), LogLevel::INFO);
+    assert_eq!(filter.get_context_level("ModuleLoader"), LogLevel::WARN);
+    assert_eq!(filter.get_context_level("UserService"), LogLevel::DEBUG);
+    assert_eq!(filter.get_context_level("Http"), LogLevel::INFO);
+
+    assert!(filter.is_enabled(LogLevel::ERROR, "ModuleLoader"));
+    assert!(!filter.is_enabled(LogLevel::INFO, "ModuleLoader"));
+    assert!(filter.is_enabled(LogLevel::DEBUG, "UserService"));
+    assert!(!filter.is_enabled(LogLevel::DEBUG, "Http"));
+
+    assert!("verbose".parse::<LogFilter>().is_err());
+    assert!("ModuleLoader=loud".parse::<LogFilter>().is_err());
+}
+
+#[tokio::test]
+async fn logger_filters_records_by_context_level() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let config = app.get::<LoggerConfig>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    config.set_filter(
+        LogFilter::new(LogLevel::INFO).with_context_level("ModuleLoader", LogLevel::WARN),
+    );
+
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.set_context("UserService");
+    logger.debug("skipped");
+    logger.info("printed");
+    logger.info_with_ctx("skipped", "ModuleLoader");
+    logger.error_with_ctx("printed", "ModuleLoader");
+
+    config.set_context_level("UserService", LogLevel::DEBUG);
+
+    logger.debug("printed");
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 3);
+    assert!(records.iter().all(|record| record.msg == "printed"));
+}
+
+#[tokio::test]
+async fn invalid_env_filter_is_logged_by_configured_logger() {
+    use crate::testing::{contains, CapturingLogger};
+
+    let capturing_logger = Ref::new(CapturingLogger::new());
+    let logger_config = Ref::new(LoggerConfig::from_env_filter(
+        "info,UserService=loud".parse::<LogFilter>(),
+    ));
+    let params = BuildParams::default()
+        .capture_logs(&capturing_logger)
+        .override_provider::<LoggerConfig, _>(logger_config);
+
+    let app = build::<PlainModule>(params).await;
+
+    let records = capturing_logger.find_by_ctx("LoggerConfig");
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, LogLevel::WARN);
+    capturing_logger.assert_logged(LogLevel::WARN, contains("loud"));
+
+    // the default filter enables all levels
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    assert!(logger.is_enabled(LogLevel::DEBUG, "UserService"));
+}
+
+#[cfg(feature = "log")]
+#[tokio::test]
+async fn log_bridge_routes_log_records_to_logger() {
+    use crate::{LogBridge, LogLogger};
+
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    LogBridge::new(logger.clone()).install().unwrap();
+
+    log::warn!(target: "UserService", user_id = 42; "user {} blocked", "bob");
+
+    // the records forwarded by `LogLogger` are not routed back by the bridge
+    app.add_log_sink(LogSink::new(
+        Ref::new(LogLogger::new()).cast::<dyn ILogger>().unwrap(),
+    ));
+
+    logger.log_record(
+        &LogRecord::new(LogLevel::ERROR, "query failed", "Database").with_field("retry", true),
+    );
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].level, LogLevel::WARN);
+    assert_eq!(records[0].ctx, "UserService");
+    assert_eq!(records[0].msg, "user bob blocked");
+    assert_eq!(records[0].get_field("user_id"), Some(&42.into()));
+
+    assert_eq!(records[1].level, LogLevel::ERROR);
+    assert_eq!(records[1].ctx, "Database");
+    assert_eq!(records[1].msg, "query failed");
+    assert_eq!(records[1].get_field("retry"), Some(&true.into()));
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn logger_layer_routes_tracing_events_to_logger() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use crate::LoggerLayer;
+
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    let layer = LoggerLayer::new(app.resolve::<Logger>().await.unwrap());
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        tracing::info!(target: "UserService", user_id = 42, admin = false, "user created");
+    });
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, LogLevel::INFO);
+    assert_eq!(records[0].ctx, "UserService");
+    assert_eq!(records[0].msg, "user created");
+    assert_eq!(records[0].get_field("user_id"), Some(&42.into()));
+    assert_eq!(records[0].get_field("admin"), Some(&false.into()));
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn logger_layer_follows_level_changes() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use crate::LoggerLayer;
+
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+    let config = app.get::<LoggerConfig>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+    config.set_level(LogLevel::INFO);
+
+    let layer = LoggerLayer::new(app.resolve::<Logger>().await.unwrap());
+
+    // the same callsite is hit before and after the level change
+    let emit = || tracing::debug!(target: "LevelChangeService", "cache refreshed");
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+        emit();
+
+        config.set_level(LogLevel::DEBUG);
+
+        emit();
+    });
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].ctx, "LevelChangeService");
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_logger_forwards_records_to_tracing() {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing_subscriber::{layer::SubscriberExt, Layer};
+
+    use crate::TracingLogger;
+
+    #[derive(Clone, Default)]
+    struct CapturingLayer {
+        events: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    struct FieldsVisitor(String);
+
+    impl Visit for FieldsVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push_str(&format!("{}={:?};", field.name(), value));
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for CapturingLayer {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut visitor = FieldsVisitor(String::new());
+
+            event.record(&mut visitor);
+
+            self.events
+                .lock()
+                .unwrap()
+                .push((event.metadata().target().to_string(), visitor.0));
+        }
+    }
+
+    let layer = CapturingLayer::default();
+
+    tracing::subscriber::with_default(tracing_subscriber::registry().with(layer.clone()), || {
+        let tracing_logger = TracingLogger::new();
+
+        tracing_logger.log_record(
+            &LogRecord::new(LogLevel::WARN, "slow query", "Database")
+                .with_field("ms", 1200)
+                .with_field("table", "users"),
+        );
+        tracing_logger.log_record(&LogRecord::new(
+            LogLevel::INFO,
+            "user created",
+            "UserService",
+        ));
+    });
+
+    let events = layer.events.lock().unwrap().clone();
+
+    assert_eq!(
+        events,
+        vec![
+            (
+                String::from("Database"),
+                String::from("message=slow query;ms=1200;table=\"users\";")
+            ),
+            (
+                String::from("UserService"),
+                String::from("message=user created;")
+            )
+        ]
+    );
+}
+
+#[tokio::test]
+async fn logger_writes_records_to_all_sinks() {
+    let build_sink = Ref::new(RecordingLogger::new());
+    let warn_sink = Ref::new(RecordingLogger::new());
+
+    let app = build::<RootModule>(BuildParams::default().log_sink(LogSink::new(
+        build_sink.clone().cast::<dyn ILogger>().unwrap(),
+    )))
+    .await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+
+    app.use_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+    app.add_log_sink(
+        LogSink::new(warn_sink.clone().cast::<dyn ILogger>().unwrap()).with_level(LogLevel::WARN),
+    );
+
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    logger.info("info");
+    logger.error("error");
+
+    // use_logger replaces only the console logger, the sinks added before are kept
+    let build_records = build_sink.records.as_ref().clone();
+
+    assert_eq!(logger.get_sinks().len(), 3);
+    assert_eq!(
+        build_records[build_records.len() - 2..]
+            .iter()
+            .map(|record| record.msg.as_str())
+            .collect::<Vec<_>>(),
+        vec!["info", "error"]
+    );
+    assert_eq!(recording_logger.records.as_ref().len(), 2);
+    assert_eq!(warn_sink.records.as_ref().len(), 1);
+    assert_eq!(warn_sink.records.as_ref()[0].msg, "error");
+}
+
+fn get_temp_log_path() -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("blackbox_di_{}", uuid::Uuid::new_v4()))
+        .join("app.log")
+}
+
+fn read_lines(path: &std::path::Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn file_logger_rotates_files_by_size() {
+    let path = get_temp_log_path();
+    let file_logger = FileLogger::new(FileLoggerOptions {
+        max_size: Some(150),
+        max_files: 2,
+        ..FileLoggerOptions::new(&path)
+    })
+    .unwrap();
+
+    for idx in 0..4 {
+        file_logger.log_record(&LogRecord::new(
+            LogLevel::INFO,
+            &format!("record {}", idx),
+            "Test",
+        ));
+    }
+
+    let current = read_lines(&path);
+    let rotated_1 = read_lines(&file_logger.get_rotated_path(1));
+    let rotated_2 = read_lines(&file_logger.get_rotated_path(2));
+
+    assert_eq!(current.len(), 1);
+    assert!(current[0].ends_with("msg=\"record 3\""));
+    assert!(rotated_1[0].ends_with("msg=\"record 2\""));
+    assert!(rotated_2[0].ends_with("msg=\"record 1\""));
+
+    // the oldest file is removed
+    assert!(!file_logger.get_rotated_path(3).exists());
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn file_logger_reports_errors_to_error_logger() {
+    let path = get_temp_log_path();
+    let recording_logger = Ref::new(RecordingLogger::new());
+    let file_logger = FileLogger::new(FileLoggerOptions {
+        max_size: Some(100),
+        max_files: 1,
+        ..FileLoggerOptions::new(&path)
+    })
+    .unwrap()
+    .with_error_logger(recording_logger.clone().cast::<dyn ILogger>().unwrap());
+
+    // the non-empty directory in place of the rotated file fails the rotation
+    let rotated_path = file_logger.get_rotated_path(1);
+
+    std::fs::create_dir_all(rotated_path.join("blocker")).unwrap();
+
+    for idx in 0..2 {
+        file_logger.log_record(&LogRecord::new(
+            LogLevel::INFO,
+            &format!("record {}", idx),
+            "Test",
+        ));
+    }
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, LogLevel::ERROR);
+    assert_eq!(records[0].ctx, "FileLogger");
+    assert!(records[0].msg.starts_with("FileLogger: failed to"));
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn file_logger_rotates_files_by_time() {
+    let path = get_temp_log_path();
+    let file_logger = FileLogger::new(FileLoggerOptions {
+        rotation: LogRotation::Daily,
+        format: LogFormat::Json,
+        ..FileLoggerOptions::new(&path)
+    })
+    .unwrap();
+
+    let mut record = LogRecord::new(LogLevel::INFO, "today", "Test");
+
+    file_logger.log_record(&record);
+
+    record.msg = String::from("tomorrow");
+    record.timestamp = record.timestamp + chrono::Duration::days(1);
+
+    file_logger.log_record(&record);
+    file_logger.log_record(&record);
+
+    assert_eq!(read_lines(&path).len(), 2);
+    assert_eq!(read_lines(&file_logger.get_rotated_path(1)).len(), 1);
+    assert!(read_lines(&file_logger.get_rotated_path(1))[0].contains("\"msg\":\"today\""));
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+struct GatedLogger {
+    entered: std::sync::Mutex<std::sync::mpsc::Sender<()>>,
+    gate: std::sync::Mutex<std::sync::mpsc::Receiver<()>>,
+    messages: std::sync::Mutex<Vec<String>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl ILogger for GatedLogger {
+    fn log_record(&self, record: &LogRecord) {
+        self.entered.lock().unwrap().send(()).unwrap();
+        self.gate.lock().unwrap().recv().unwrap();
+        self.messages.lock().unwrap().push(record.msg.clone());
+    }
+    fn set_context(&self, _ctx: &str) {}
+    fn get_context(&self) -> String {
+        String::new()
+    }
+}
+
+fn log_with_overflow(overflow: OverflowPolicy) -> (Vec<String>, u64) {
+    let (entered_tx, entered_rx) = std::sync::mpsc::channel();
+    let (gate_tx, gate_rx) = std::sync::mpsc::channel();
+
+    let gated_logger = Ref::new(GatedLogger {
+        entered: std::sync::Mutex::new(entered_tx),
+        gate: std::sync::Mutex::new(gate_rx),
+        messages: std::sync::Mutex::new(Vec::new()),
+    });
+
+    let async_logger = std::sync::Arc::new(AsyncLogger::new(
+        gated_logger.clone().cast::<dyn ILogger>().unwrap(),
+        AsyncLoggerOptions {
+            capacity: 2,
+            overflow,
+        },
+    ));
+
+    let log = |logger: &AsyncLogger, msg: &str| {
+        logger.log_record(&LogRecord::new(LogLevel::INFO, msg, "Test"));
+    };
+
+    // the first record is taken by the background thread and blocks it
+    log(&async_logger, "0");
+    entered_rx.recv().unwrap();
+
+    let mut producer = Some({
+        let async_logger = async_logger.clone();
+
+        std::thread::spawn(move || {
+            for msg in ["1", "2", "3", "4"] {
+                log(&async_logger, msg);
+            }
+        })
+    });
+
+    // the blocked producer waits for the background thread
+    if overflow != OverflowPolicy::Block {
+        producer.take().unwrap().join().unwrap();
+    }
+
+    for _ in 0..5 {
+        gate_tx.send(()).unwrap();
+    }
+
+    if let Some(producer) = producer {
+        producer.join().unwrap();
+    }
+
+    async_logger.flush();
+
+    let messages = gated_logger.messages.lock().unwrap().clone();
+
+    (messages, async_logger.get_dropped_count())
+}
+
+#[test]
+fn async_logger_applies_overflow_policy() {
+    assert_eq!(
+        log_with_overflow(OverflowPolicy::Drop),
+        (
+            vec![String::from("0"), String::from("1"), String::from("2")],
+            2
+        )
+    );
+    assert_eq!(
+        log_with_overflow(OverflowPolicy::DropOldest),
+        (
+            vec![String::from("0"), String::from("3"), String::from("4")],
+            2
+        )
+    );
+    assert_eq!(
+        log_with_overflow(OverflowPolicy::Block).0,
+        vec!["0", "1", "2", "3", "4"]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn async_logger_block_policy_does_not_stall_runtime() {
+    let (entered_tx, entered_rx) = std::sync::mpsc::channel();
+    let (gate_tx, gate_rx) = std::sync::mpsc::channel();
+
+    let gated_logger = Ref::new(GatedLogger {
+        entered: std::sync::Mutex::new(entered_tx),
+        gate: std::sync::Mutex::new(gate_rx),
+        messages: std::sync::Mutex::new(Vec::new()),
+    });
+
+    let async_logger = std::sync::Arc::new(AsyncLogger::new(
+        gated_logger.clone().cast::<dyn ILogger>().unwrap(),
+        AsyncLoggerOptions {
+            capacity: 1,
+            overflow: OverflowPolicy::Block,
+        },
+    ));
+    let about_to_block = std::sync::Arc::new(tokio::sync::Notify::new());
+
+    // both tasks share the only worker thread
+    let producer = {
+        let async_logger = async_logger.clone();
+        let about_to_block = about_to_block.clone();
+
+        tokio::spawn(async move {
+            let log = |msg: &str| {
+                async_logger.log_record(&LogRecord::new(LogLevel::INFO, msg, "Test"));
+            };
+
+            log("0");
+            entered_rx.recv().unwrap();
+            log("1");
+
+            about_to_block.notify_one();
+
+            // the channel is full until the gate is opened by the other task
+            log("2");
+
+            entered_rx
+        })
+    };
+
+    let opener = tokio::spawn(async move {
+        about_to_block.notified().await;
+
+        for _ in 0..3 {
+            gate_tx.send(()).unwrap();
+        }
+    });
+
+    // the gated logger reports the entered records until the end
+    let _entered_rx = producer.await.unwrap();
+    opener.await.unwrap();
+
+    async_logger.flush();
+
+    assert_eq!(
+        gated_logger.messages.lock().unwrap().clone(),
+        vec!["0", "1", "2"]
+    );
+}
+
+#[tokio::test]
+async fn async_logger_is_flushed_on_app_close() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let recording_logger = app.get::<RecordingLogger>().unwrap();
+
+    let async_logger = AsyncLogger::new(
+        recording_logger.clone().cast::<dyn ILogger>().unwrap(),
+        AsyncLoggerOptions::default(),
+    );
+
+    app.use_logger(Ref::new(async_logger).cast::<dyn ILogger>().unwrap());
+
+    let logger = app.resolve::<Logger>().await.unwrap();
+
+    for idx in 0..100 {
+        logger.info(&format!("record {}", idx));
+    }
+
+    app.close().await;
+
+    let records = recording_logger.records.as_ref().clone();
+
+    assert_eq!(records.len(), 100);
+    assert_eq!(records[99].msg, "record 99");
+}
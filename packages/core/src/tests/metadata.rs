@@ -0,0 +1,98 @@
+use crate::{
+    app::{build, BuildParams},
+    implements, injectable, interface,
+    metadata::MetadataValue,
+    module,
+    tokens::get_token,
+    DiscoveryService,
+};
+
+#[interface]
+#[blackbox_di(crate)]
+trait IJob {
+    fn run(&self);
+}
+
+#[injectable]
+#[metadata(role = "admin", queue = "emails")]
+#[metadata(retries = 3, timeout = 1.5, enabled = true)]
+#[blackbox_di(crate)]
+struct EmailJob {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl EmailJob {
+    #[metadata(route = "/emails")]
+    fn list(&self) {}
+
+    fn internal(&self) {}
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IJob for EmailJob {
+    #[metadata(cron = "0 * * * * *")]
+    fn run(&self) {}
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repo {}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    email_job: EmailJob,
+
+    #[provider]
+    repo: Repo,
+}
+
+#[tokio::test]
+async fn reads_provider_metadata() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let metadata = app.get_metadata::<EmailJob>().unwrap();
+
+    assert_eq!(metadata.get::<String>("role"), Some(String::from("admin")));
+    assert_eq!(
+        metadata.get::<String>("queue"),
+        Some(String::from("emails"))
+    );
+    assert_eq!(metadata.get::<u32>("retries"), Some(3));
+    assert_eq!(metadata.get::<f64>("timeout"), Some(1.5));
+    assert_eq!(metadata.get::<bool>("enabled"), Some(true));
+    assert_eq!(metadata.get::<bool>("role"), None);
+    assert_eq!(metadata.get::<String>("missing"), None);
+
+    assert!(app.get_metadata::<Repo>().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn discovers_metadata_of_providers_and_methods() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let discovery = app.get::<DiscoveryService>().unwrap();
+
+    let providers = discovery.get_providers_with_metadata("role");
+
+    assert_eq!(providers.len(), 1);
+    assert_eq!(providers[0].get_token(), get_token::<EmailJob>());
+    assert_eq!(
+        providers[0]
+            .get_method_metadata("list")
+            .unwrap()
+            .get_value("route"),
+        Some(&MetadataValue::from("/emails"))
+    );
+    assert!(providers[0].get_method_metadata("internal").is_none());
+
+    let methods = discovery.get_methods_with_metadata("cron");
+
+    assert_eq!(methods.len(), 1);
+    assert_eq!(methods[0].1, "run");
+    assert_eq!(
+        methods[0].2.get::<String>("cron"),
+        Some(String::from("0 * * * * *"))
+    );
+}
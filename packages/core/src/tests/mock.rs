@@ -0,0 +1,128 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::Ref,
+    implements, injectable, interface, module,
+};
+
+#[interface(mock)]
+#[blackbox_di(crate)]
+trait IRepo {
+    fn find(&self, id: u32) -> Option<String>;
+    fn save(&self, name: &str);
+    async fn count(&self) -> usize;
+    fn describe(&self) -> String {
+        String::from("repo")
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repo {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IRepo for Repo {
+    fn find(&self, _id: u32) -> Option<String> {
+        Some(String::from("real"))
+    }
+    fn save(&self, _name: &str) {}
+    async fn count(&self) -> usize {
+        0
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct UserService {
+    #[inject(use Repo)]
+    repo: Ref<dyn IRepo>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl UserService {
+    async fn rename(&self, id: u32) -> Option<String> {
+        let name = self.repo.find(id)?;
+        let new_name = format!("{}!", name);
+
+        self.repo.save(&new_name);
+
+        Some(new_name)
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    repo: Repo,
+    #[provider]
+    user_service: UserService,
+}
+
+#[tokio::test]
+async fn mock_stubs_sync_and_async_methods() {
+    let mock = MockIRepo::new();
+
+    mock.expect_find()
+        .with(|(id,)| *id == 1)
+        .return_const(Some(String::from("Alice")));
+    mock.expect_find().returning(|_| None);
+    mock.expect_count().return_const(2);
+    mock.expect_save().with(|(name,)| name == "Bob").times(1);
+
+    assert_eq!(mock.find(1), Some(String::from("Alice")));
+    assert_eq!(mock.find(2), None);
+    assert_eq!(mock.count().await, 2);
+    assert_eq!(mock.describe(), "repo");
+
+    mock.save("Bob");
+
+    assert_eq!(mock.find_calls(), 2);
+    assert_eq!(mock.save_calls(), 1);
+
+    mock.verify();
+}
+
+#[test]
+#[should_panic(expected = "MockIRepo::save: expected 2 calls, but the method was called 1 times")]
+fn mock_verifies_call_count() {
+    let mock = MockIRepo::new();
+
+    mock.expect_save().times(2);
+    mock.save("Bob");
+    mock.verify();
+}
+
+#[test]
+#[should_panic(expected = "MockIRepo::find: no return value is set")]
+fn mock_panics_without_return_value() {
+    MockIRepo::new().find(1);
+}
+
+#[tokio::test]
+async fn mock_overrides_provider() {
+    let mock = Ref::new(MockIRepo::new());
+
+    mock.expect_find().return_const(Some(String::from("Alice")));
+    mock.expect_save().with(|(name,)| name == "Alice!").times(1);
+
+    let app =
+        build::<RootModule>(BuildParams::default().override_provider::<Repo, _>(mock.clone()))
+            .await;
+    let user_service = app.get::<UserService>().unwrap();
+
+    assert_eq!(user_service.rename(1).await, Some(String::from("Alice!")));
+    // the instance behind the Repo token is the mock
+    assert!(app.get::<Repo>().is_err());
+
+    mock.verify();
+}
+
+#[tokio::test]
+async fn real_provider_is_used_without_override() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let user_service = app.get::<UserService>().unwrap();
+
+    assert_eq!(user_service.rename(1).await, Some(String::from("real!")));
+}
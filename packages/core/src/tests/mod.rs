@@ -0,0 +1,18 @@
+#![allow(unused)]
+
+#[cfg(feature = "config")]
+mod config;
+mod discovery;
+mod event_emitter;
+mod health;
+mod intercept;
+mod logger;
+mod metadata;
+#[cfg(feature = "mock")]
+mod mock;
+mod module_ref;
+mod resolve;
+#[cfg(feature = "schedule")]
+mod schedule;
+mod select;
+mod testing;
@@ -0,0 +1,188 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    implements, injectable, interface, module,
+    tokens::get_token,
+    ModuleRef,
+};
+
+#[interface]
+#[blackbox_di(crate)]
+trait IStrategy {
+    fn name(&self) -> String;
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct FastStrategy {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IStrategy for FastStrategy {
+    fn name(&self) -> String {
+        String::from("fast")
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct SafeStrategy {}
+
+#[implements]
+#[blackbox_di(crate)]
+impl IStrategy for SafeStrategy {
+    fn name(&self) -> String {
+        String::from("safe")
+    }
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct Session {
+    id: RefMut<u32>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Session {
+    #[factory]
+    fn new() -> Session {
+        Session { id: RefMut::new(0) }
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Hidden {}
+
+#[module]
+#[blackbox_di(crate)]
+struct HiddenModule {
+    #[provider]
+    hidden: Hidden,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Selector {
+    #[inject]
+    module_ref: Ref<ModuleRef>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Selector {
+    fn select(&self, name: &str) -> Result<Ref<dyn IStrategy>, String> {
+        self.module_ref
+            .get_by_token::<dyn IStrategy>(&format!("{}_STRATEGY", name.to_uppercase()))
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct StrategyModule {
+    #[import]
+    hidden_module: HiddenModule,
+
+    #[provider("FAST_STRATEGY")]
+    fast: FastStrategy,
+
+    #[provider("SAFE_STRATEGY")]
+    safe: SafeStrategy,
+
+    #[provider]
+    session: Session,
+
+    #[provider]
+    #[export]
+    selector: Selector,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    strategy_module: StrategyModule,
+}
+
+#[tokio::test]
+async fn module_ref_resolves_providers_by_runtime_token() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let selector = app.get::<Selector>().unwrap();
+
+    assert_eq!(
+        selector.module_ref.get_host().unwrap().as_ref().get_token(),
+        get_token::<StrategyModule>()
+    );
+    assert_eq!(selector.select("fast").unwrap().name(), "fast");
+    assert_eq!(selector.select("safe").unwrap().name(), "safe");
+    assert!(selector.select("slow").is_err());
+}
+
+#[tokio::test]
+async fn module_ref_resolves_transient_providers() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let selector = app.get::<Selector>().unwrap();
+
+    assert!(selector.module_ref.get::<Session>().is_err());
+
+    let session_1 = selector.module_ref.resolve::<Session>().await.unwrap();
+    let session_2 = selector.module_ref.resolve::<Session>().await.unwrap();
+
+    *session_1.id.as_mut() = 1;
+
+    assert_eq!(*session_2.id.as_ref(), 0);
+}
+
+#[tokio::test]
+async fn module_ref_strict_lookup_is_limited_by_host_module() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let selector = app.get::<Selector>().unwrap();
+
+    // the logger is available from the global core module
+    assert!(selector.module_ref.resolve::<crate::Logger>().await.is_ok());
+    assert!(selector
+        .module_ref
+        .strict()
+        .unwrap()
+        .resolve::<crate::Logger>()
+        .await
+        .is_err());
+
+    assert!(selector
+        .module_ref
+        .strict()
+        .unwrap()
+        .get::<Selector>()
+        .is_ok());
+
+    // non-exported providers of the imported modules are not available
+    assert!(selector.module_ref.get::<Hidden>().is_err());
+}
+
+#[test]
+fn module_ref_does_not_keep_container_alive() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let (selector, container) = runtime.block_on(async {
+        let app = build::<RootModule>(BuildParams::default()).await;
+        let selector = app.get::<Selector>().unwrap();
+
+        (selector, app.get_container().downgrade())
+    });
+
+    // the runtime drops the app held by the exit signal listener
+    drop(runtime);
+
+    assert!(container.upgrade().is_none());
+    assert!(selector.module_ref.get::<Selector>().is_err());
+    assert!(selector.module_ref.get_context().is_err());
+    assert!(selector.module_ref.strict().is_err());
+    assert!(selector
+        .module_ref
+        .get_host()
+        .err()
+        .unwrap()
+        .starts_with("ModuleRef: "));
+}
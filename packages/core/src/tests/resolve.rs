@@ -0,0 +1,154 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    implements, injectable, module,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repo {}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct Counter {
+    #[inject]
+    repo: Ref<Repo>,
+
+    value: RefMut<u32>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Counter {
+    #[factory]
+    fn new(repo: Ref<Repo>) -> Counter {
+        Counter {
+            repo,
+            value: RefMut::new(0),
+        }
+    }
+
+    fn increment(&self) -> u32 {
+        *self.value.as_mut() += 1;
+        *self.value.as_ref()
+    }
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct Service {
+    #[inject]
+    counter: Ref<Counter>,
+}
+
+#[injectable]
+#[scope(Transient)]
+#[blackbox_di(crate)]
+struct AuditScope {
+    inquirer: crate::inquirer::Inquirer,
+}
+
+mod survey {
+    pub struct Inquirer {
+        pub question: String,
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Survey {
+    #[inject]
+    audit: Ref<AuditScope>,
+
+    // not the DI inquirer, only the name is the same
+    inquirer: survey::Inquirer,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Survey {
+    #[factory]
+    fn new(audit: Ref<AuditScope>) -> Survey {
+        Survey {
+            audit,
+            inquirer: survey::Inquirer {
+                question: String::from("How are you?"),
+            },
+        }
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    repo: Repo,
+
+    #[provider]
+    counter: Counter,
+
+    #[provider]
+    service: Service,
+
+    #[provider]
+    audit_scope: AuditScope,
+
+    #[provider]
+    survey: Survey,
+}
+
+#[tokio::test]
+async fn resolve_creates_new_transient_instances() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let counter_1 = app.resolve::<Counter>().await.unwrap();
+    let counter_2 = app.resolve::<Counter>().await.unwrap();
+
+    assert_eq!(counter_1.increment(), 1);
+    assert_eq!(counter_1.increment(), 2);
+    assert_eq!(counter_2.increment(), 1);
+}
+
+#[tokio::test]
+async fn resolve_links_dependency_subtree() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let repo = app.get::<Repo>().unwrap();
+    let service_1 = app.resolve::<Service>().await.unwrap();
+    let service_2 = app.resolve::<Service>().await.unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(&service_1.counter.repo, &repo));
+    assert_eq!(service_1.counter.increment(), 1);
+    assert_eq!(service_2.counter.increment(), 1);
+}
+
+#[tokio::test]
+async fn resolve_returns_shared_singleton_instance() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let repo_1 = app.resolve::<Repo>().await.unwrap();
+    let repo_2 = app.get::<Repo>().unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(&repo_1, &repo_2));
+}
+
+#[tokio::test]
+async fn get_fails_for_transient_provider() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let error = app.get::<Counter>().err().unwrap();
+
+    assert!(error.contains("transient"));
+}
+
+#[tokio::test]
+async fn inquirer_is_passed_only_to_marked_or_qualified_deps() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let survey = app.get::<Survey>().unwrap();
+
+    assert_eq!(survey.audit.inquirer.get_name(), "Survey");
+    assert_eq!(survey.inquirer.question, "How are you?");
+}
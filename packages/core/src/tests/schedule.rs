@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use crate::{
+    app::{build, BuildParams},
+    cell::RefMut,
+    events::OnModuleInit,
+    implements, injectable, module,
+    modules::schedule::{JobTrigger, ScheduleModule, SchedulerRegistry},
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Jobs {
+    ticks: RefMut<u32>,
+    timeouts: RefMut<u32>,
+    initialized: RefMut<bool>,
+    ticks_before_init: RefMut<Option<u32>>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Jobs {
+    #[factory]
+    fn new() -> Jobs {
+        Jobs {
+            ticks: RefMut::new(0),
+            timeouts: RefMut::new(0),
+            initialized: RefMut::new(false),
+            ticks_before_init: RefMut::new(None),
+        }
+    }
+
+    #[interval(ms = 100, name = "tick")]
+    async fn tick(&self) {
+        if !*self.initialized.as_ref() {
+            *self.ticks_before_init.as_mut() = Some(*self.ticks.as_ref());
+        }
+
+        *self.ticks.as_mut() += 1;
+    }
+
+    #[timeout(ms = 250)]
+    fn once(&self) -> Result<(), String> {
+        *self.timeouts.as_mut() += 1;
+
+        Err(String::from("failed run is logged"))
+    }
+
+    #[cron("0 0 0 1 1 *", name = "new_year")]
+    fn congratulate(&self) {}
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnModuleInit for Jobs {
+    async fn on_module_init(&self) {
+        sleep(Duration::from_millis(150)).await;
+
+        *self.initialized.as_mut() = true;
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    schedule_module: ScheduleModule,
+
+    #[provider]
+    jobs: Jobs,
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct CronJobs {
+    runs: RefMut<u32>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl CronJobs {
+    #[factory]
+    fn new() -> CronJobs {
+        CronJobs {
+            runs: RefMut::new(0),
+        }
+    }
+
+    #[cron("* * * * * *")]
+    async fn every_second(&self) {
+        *self.runs.as_mut() += 1;
+
+        // the runs missed by the slow run are skipped
+        if *self.runs.as_ref() == 1 {
+            sleep(Duration::from_millis(2500)).await;
+        }
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct CronModule {
+    #[import]
+    schedule_module: ScheduleModule,
+
+    #[provider]
+    cron_jobs: CronJobs,
+}
+
+#[tokio::test(start_paused = true)]
+async fn scheduler_runs_jobs_after_init() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let jobs = app.get::<Jobs>().unwrap();
+
+    sleep(Duration::from_millis(350)).await;
+
+    assert_eq!(*jobs.ticks.as_ref(), 3);
+    assert_eq!(*jobs.timeouts.as_ref(), 1);
+    assert_eq!(*jobs.ticks_before_init.as_ref(), None);
+
+    let registry = app.get::<SchedulerRegistry>().unwrap();
+    let names = registry
+        .get_jobs()
+        .into_iter()
+        .map(|job| job.name)
+        .collect::<Vec<_>>();
+
+    assert_eq!(names.len(), 3);
+    assert!(names.contains(&String::from("tick")));
+    assert!(names.contains(&String::from("new_year")));
+    assert!(names.iter().any(|name| name.ends_with("Jobs::once")));
+
+    assert_eq!(
+        registry.get_job("new_year").unwrap().trigger,
+        JobTrigger::Cron(String::from("0 0 0 1 1 *"))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn scheduler_registry_pauses_and_cancels_jobs() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let jobs = app.get::<Jobs>().unwrap();
+    let registry = app.get::<SchedulerRegistry>().unwrap();
+
+    registry.pause("tick").unwrap();
+    sleep(Duration::from_millis(250)).await;
+
+    assert_eq!(*jobs.ticks.as_ref(), 0);
+    assert!(registry.get_job("tick").unwrap().paused);
+
+    registry.resume("tick").unwrap();
+    sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(*jobs.ticks.as_ref(), 1);
+
+    registry.cancel("tick").unwrap();
+    sleep(Duration::from_millis(300)).await;
+
+    assert_eq!(*jobs.ticks.as_ref(), 1);
+    assert!(!registry.has_job("tick"));
+    assert!(registry.cancel("tick").is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn scheduler_stops_jobs_on_close() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+    let jobs = app.get::<Jobs>().unwrap();
+
+    app.close().await;
+    sleep(Duration::from_millis(500)).await;
+
+    assert_eq!(*jobs.ticks.as_ref(), 0);
+    assert!(app
+        .get::<SchedulerRegistry>()
+        .unwrap()
+        .get_jobs()
+        .is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn scheduler_fires_cron_jobs() {
+    let app = build::<CronModule>(BuildParams::default()).await;
+    let cron_jobs = app.get::<CronJobs>().unwrap();
+
+    // the first run is in the first second and takes 2.5 seconds
+    sleep(Duration::from_millis(1000)).await;
+
+    assert_eq!(*cron_jobs.runs.as_ref(), 1);
+
+    sleep(Duration::from_millis(5000)).await;
+
+    assert_eq!(*cron_jobs.runs.as_ref(), 4);
+}
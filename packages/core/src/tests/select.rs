@@ -0,0 +1,137 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::{Ref, RefMut},
+    implements, injectable, module,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct Repo {
+    name: RefMut<String>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl Repo {
+    #[factory]
+    fn new() -> Repo {
+        Repo {
+            name: RefMut::new(String::new()),
+        }
+    }
+}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct UserService {}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct AccountService {}
+
+#[injectable]
+#[blackbox_di(crate)]
+struct RootService {}
+
+#[module]
+#[blackbox_di(crate)]
+struct UserModule {
+    #[provider]
+    repo: Repo,
+
+    #[provider]
+    #[export]
+    user_service: UserService,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct AccountModule {
+    #[provider]
+    repo: Repo,
+
+    #[provider]
+    account_service: AccountService,
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[import]
+    user_module: UserModule,
+
+    #[import]
+    account_module: AccountModule,
+
+    #[provider]
+    root_service: RootService,
+}
+
+#[tokio::test]
+async fn unscoped_get_fails_for_ambiguous_provider() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let error = app.get::<Repo>().err().unwrap();
+
+    assert!(error.contains("ambiguous"));
+    assert!(error.contains("UserModule"));
+    assert!(error.contains("AccountModule"));
+}
+
+#[tokio::test]
+async fn select_retrieves_provider_of_the_module() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let user_repo = app.select::<UserModule>().unwrap().get::<Repo>().unwrap();
+    let account_repo = app
+        .select::<AccountModule>()
+        .unwrap()
+        .get::<Repo>()
+        .unwrap();
+
+    *user_repo.name.as_mut() = String::from("users");
+
+    assert_eq!(*account_repo.name.as_ref(), "");
+    assert!(!std::sync::Arc::ptr_eq(&user_repo, &account_repo));
+}
+
+#[tokio::test]
+async fn select_respects_exported_providers() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    let root = app.select::<RootModule>().unwrap();
+
+    assert!(root.get::<UserService>().is_ok());
+    assert!(root.get::<AccountService>().is_err());
+    assert!(root.get::<Repo>().is_err());
+
+    assert!(root.strict().get::<RootService>().is_ok());
+    assert!(root.strict().get::<UserService>().is_err());
+}
+
+#[tokio::test]
+async fn strict_app_hides_non_exported_providers() {
+    let app = build::<RootModule>(BuildParams::default().strict()).await;
+
+    assert!(app.get::<RootService>().is_ok());
+    assert!(app.get::<UserService>().is_ok());
+    assert!(app.get::<AccountService>().is_err());
+    assert!(app.get::<Repo>().is_err());
+
+    assert!(app
+        .select::<AccountModule>()
+        .unwrap()
+        .get::<AccountService>()
+        .is_ok());
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct DetachedModule {}
+
+#[tokio::test]
+async fn select_fails_for_unknown_module() {
+    let app = build::<RootModule>(BuildParams::default()).await;
+
+    assert!(app.select::<DetachedModule>().is_err());
+}
@@ -0,0 +1,62 @@
+use crate::{
+    app::{build, BuildParams},
+    cell::Ref,
+    events::OnModuleInit,
+    implements, injectable, module,
+    testing::{any_msg, contains, eq, CapturingLogger},
+    ILogger, LogLevel, Logger,
+};
+
+#[injectable]
+#[blackbox_di(crate)]
+struct PaymentService {
+    #[inject]
+    logger: Ref<Logger>,
+}
+
+#[implements]
+#[blackbox_di(crate)]
+impl OnModuleInit for PaymentService {
+    async fn on_module_init(&self) {
+        self.logger.warn("gateway is slow, retry in 5s");
+    }
+}
+
+#[module]
+#[blackbox_di(crate)]
+struct RootModule {
+    #[provider]
+    payment_service: PaymentService,
+}
+
+#[tokio::test]
+async fn capturing_logger_captures_bootstrap_logs() {
+    let capturing_logger = Ref::new(CapturingLogger::new());
+    let app = build::<RootModule>(BuildParams::default().capture_logs(&capturing_logger)).await;
+
+    capturing_logger.assert_logged(LogLevel::WARN, contains("retry"));
+    capturing_logger.assert_not_logged(LogLevel::ERROR, any_msg());
+
+    let records = capturing_logger.find_by_ctx("PaymentService");
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].msg, "gateway is slow, retry in 5s");
+
+    let payment_service = app.get::<PaymentService>().unwrap();
+
+    capturing_logger.clear();
+    payment_service.logger.info("charged");
+
+    assert!(capturing_logger.is_logged(LogLevel::INFO, eq("charged")));
+    assert!(!capturing_logger.is_logged(LogLevel::INFO, eq("charge")));
+    assert_eq!(capturing_logger.get_records().len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "no WARN record with message containing \"retry\" was logged")]
+fn capturing_logger_panics_if_record_is_missing() {
+    let capturing_logger = CapturingLogger::new();
+
+    capturing_logger.log_with_ctx(LogLevel::INFO, "retry", "Http");
+    capturing_logger.assert_logged(LogLevel::WARN, contains("retry"));
+}